@@ -1,5 +1,7 @@
 use casper_types::{U256, U512};
 
+use crate::error::BondingCurveError;
+
 /// Curve type identifier
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
@@ -7,6 +9,9 @@ pub enum CurveType {
     Linear = 0,
     Sigmoid = 1,
     Steep = 2,
+    Exponential = 3,
+    Logarithmic = 4,
+    Piecewise = 5,
 }
 
 impl CurveType {
@@ -15,14 +20,168 @@ impl CurveType {
             0 => Some(CurveType::Linear),
             1 => Some(CurveType::Sigmoid),
             2 => Some(CurveType::Steep),
+            3 => Some(CurveType::Exponential),
+            4 => Some(CurveType::Logarithmic),
+            5 => Some(CurveType::Piecewise),
             _ => None,
         }
     }
 }
 
+/// Piecewise-linear control point: (progress in basis points, price in motes per token)
+pub type ControlPoint = (u64, U512);
+
+/// Maximum number of control points a `Piecewise` curve may define
+pub const MAX_CONTROL_POINTS: usize = 16;
+
+const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Normalized shapes (parts per million of the price range) sampled every 10%
+/// of progress. Exponential is `(e^4t - 1) / (e^4 - 1)`, Logarithmic is
+/// `log10(1 + 9t)`; prices between samples are interpolated linearly.
+const SHAPE_SCALE: u64 = 1_000_000;
+const EXPONENTIAL_SHAPE: [u64; 11] = [
+    0, 9_176, 22_865, 43_287, 73_753, 119_203, 187_006, 288_156, 439_055, 664_169, 1_000_000,
+];
+const LOGARITHMIC_SHAPE: [u64; 11] = [
+    0, 278_754, 447_158, 568_202, 662_758, 740_363, 806_180, 863_323, 913_814, 959_041, 1_000_000,
+];
+
+/// Check that custom control points start at 0 bps, end at 10000 bps, have
+/// strictly increasing progress and non-zero, non-decreasing prices
+pub fn validate_control_points(points: &[ControlPoint]) -> Result<(), BondingCurveError> {
+    if points.len() < 2 || points.len() > MAX_CONTROL_POINTS {
+        return Err(BondingCurveError::InvalidCurvePoints);
+    }
+    if points[0].0 != 0 || points[points.len() - 1].0 != BPS_DENOMINATOR || points[0].1.is_zero() {
+        return Err(BondingCurveError::InvalidCurvePoints);
+    }
+    for window in points.windows(2) {
+        if window[1].0 <= window[0].0 || window[1].1 < window[0].1 {
+            return Err(BondingCurveError::InvalidCurvePoints);
+        }
+    }
+    Ok(())
+}
+
 /// Precision multiplier for fixed-point arithmetic (18 decimals)
 const PRECISION: u128 = 1_000_000_000_000_000_000u128;
 
+/// Fixed-point scale for curve progress inside the integrals (36 decimals)
+const INTEGRAL_SCALE: u128 = 1_000_000_000_000_000_000_000_000_000_000_000_000u128;
+
+/// Token amounts carry 18 decimals; prices are quoted per whole token
+const TOKEN_UNIT: u128 = 1_000_000_000_000_000_000u128;
+
+// ============ Checked Arithmetic ============
+
+/// Widen a token amount to U512 without truncation
+pub fn to_u512(value: U256) -> U512 {
+    let mut bytes = [0u8; 32];
+    value.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
+
+/// Narrow a U512 token amount back to U256
+pub fn to_u256(value: U512) -> Result<U256, BondingCurveError> {
+    if value.bits() > 256 {
+        return Err(BondingCurveError::Overflow);
+    }
+    let mut bytes = [0u8; 64];
+    value.to_little_endian(&mut bytes);
+    Ok(U256::from_little_endian(&bytes[..32]))
+}
+
+fn add(a: U512, b: U512) -> Result<U512, BondingCurveError> {
+    a.checked_add(b).ok_or(BondingCurveError::Overflow)
+}
+
+fn sub(a: U512, b: U512) -> Result<U512, BondingCurveError> {
+    a.checked_sub(b).ok_or(BondingCurveError::Overflow)
+}
+
+fn mul(a: U512, b: U512) -> Result<U512, BondingCurveError> {
+    a.checked_mul(b).ok_or(BondingCurveError::Overflow)
+}
+
+fn div(a: U512, b: U512) -> Result<U512, BondingCurveError> {
+    a.checked_div(b).ok_or(BondingCurveError::DivisionByZero)
+}
+
+/// Control points of the curves interpolated piecewise-linearly: the sampled
+/// shape for Exponential and Logarithmic, the creator's points for Piecewise
+fn control_points(
+    curve_type: CurveType,
+    base_price: U512,
+    max_price: U512,
+    custom: &[ControlPoint],
+) -> Result<([ControlPoint; MAX_CONTROL_POINTS], usize), BondingCurveError> {
+    let mut points = [(0u64, U512::zero()); MAX_CONTROL_POINTS];
+    let shape = match curve_type {
+        CurveType::Exponential => &EXPONENTIAL_SHAPE,
+        CurveType::Logarithmic => &LOGARITHMIC_SHAPE,
+        CurveType::Piecewise => {
+            validate_control_points(custom)?;
+            points[..custom.len()].copy_from_slice(custom);
+            return Ok((points, custom.len()));
+        }
+        _ => return Err(BondingCurveError::InvalidCurveType),
+    };
+
+    let price_range = sub(max_price, base_price)?;
+    let step = BPS_DENOMINATOR / (shape.len() as u64 - 1);
+    for (i, value) in shape.iter().enumerate() {
+        let price = add(base_price, div(mul(price_range, U512::from(*value))?, U512::from(SHAPE_SCALE))?)?;
+        points[i] = (i as u64 * step, price);
+    }
+    Ok((points, shape.len()))
+}
+
+/// Price at `progress` (scaled by `precision`) interpolated between control points
+fn interpolate_price(points: &[ControlPoint], progress: U512, precision: U512) -> Result<U512, BondingCurveError> {
+    let bps = U512::from(BPS_DENOMINATOR);
+    for window in points.windows(2) {
+        let (start_bps, start_price) = window[0];
+        let (end_bps, end_price) = window[1];
+        let start = div(mul(U512::from(start_bps), precision)?, bps)?;
+        let end = div(mul(U512::from(end_bps), precision)?, bps)?;
+        if progress <= end {
+            let offset = progress.saturating_sub(start);
+            let rise = div(mul(sub(end_price, start_price)?, offset)?, sub(end, start)?)?;
+            return add(start_price, rise);
+        }
+    }
+    Ok(points[points.len() - 1].1)
+}
+
+/// Area under the interpolated price from progress 0 to `t`, both scaled by `scale`.
+/// Whole segments are exact trapezoids and the partial segment is rounded down,
+/// which keeps the area continuous and monotone in `t`.
+fn interpolated_area(points: &[ControlPoint], t: U512, scale: U512) -> Result<U512, BondingCurveError> {
+    let bps = U512::from(BPS_DENOMINATOR);
+    let two = U512::from(2u64);
+    let mut area = U512::zero();
+    for window in points.windows(2) {
+        let (start_bps, start_price) = window[0];
+        let (end_bps, end_price) = window[1];
+        let start = div(mul(U512::from(start_bps), scale)?, bps)?;
+        let end = div(mul(U512::from(end_bps), scale)?, bps)?;
+        let width = sub(end, start)?;
+        let rise = sub(end_price, start_price)?;
+        if t >= end {
+            area = add(area, div(mul(width, add(start_price, end_price)?)?, two)?)?;
+        } else {
+            let offset = sub(t, start)?;
+            let slope_area = div(mul(mul(rise, offset)?, offset)?, mul(two, width)?)?;
+            area = add(area, add(mul(offset, start_price)?, slope_area)?)?;
+            break;
+        }
+    }
+    Ok(area)
+}
+
+// ============ Spot Price ============
+
 /// Calculate the current spot price based on curve type and progress
 ///
 /// # Arguments
@@ -31,6 +190,7 @@ const PRECISION: u128 = 1_000_000_000_000_000_000u128;
 /// * `total_supply` - Total supply available for the curve
 /// * `base_price` - Starting price (in motes per token)
 /// * `max_price` - Maximum price at full supply (in motes per token)
+/// * `points` - Control points of a `Piecewise` curve (ignored otherwise)
 ///
 /// # Returns
 /// Current price in motes per token (U512)
@@ -40,23 +200,25 @@ pub fn calculate_price(
     total_supply: U256,
     base_price: U512,
     max_price: U512,
-) -> U512 {
+    points: &[ControlPoint],
+) -> Result<U512, BondingCurveError> {
     if total_supply.is_zero() {
-        return base_price;
+        return Ok(base_price);
     }
 
     // Calculate progress as a fraction with PRECISION
     // progress = (tokens_sold * PRECISION) / total_supply
-    let tokens_sold_u512 = U512::from(tokens_sold.as_u128());
-    let total_supply_u512 = U512::from(total_supply.as_u128());
     let precision = U512::from(PRECISION);
-
-    let progress = (tokens_sold_u512 * precision) / total_supply_u512;
+    let progress = div(mul(to_u512(tokens_sold), precision)?, to_u512(total_supply))?;
 
     match curve_type {
         CurveType::Linear => calculate_linear_price(progress, base_price, max_price, precision),
         CurveType::Sigmoid => calculate_sigmoid_price(progress, base_price, max_price, precision),
         CurveType::Steep => calculate_steep_price(progress, base_price, max_price, precision),
+        CurveType::Exponential | CurveType::Logarithmic | CurveType::Piecewise => {
+            let (points, len) = control_points(curve_type, base_price, max_price, points)?;
+            interpolate_price(&points[..len], progress, precision)
+        }
     }
 }
 
@@ -67,9 +229,9 @@ fn calculate_linear_price(
     base_price: U512,
     max_price: U512,
     precision: U512,
-) -> U512 {
-    let price_range = max_price - base_price;
-    base_price + (progress * price_range) / precision
+) -> Result<U512, BondingCurveError> {
+    let price_range = sub(max_price, base_price)?;
+    add(base_price, div(mul(progress, price_range)?, precision)?)
 }
 
 /// Sigmoid curve: S-shaped curve with slow start, rapid middle, slow end
@@ -80,27 +242,21 @@ fn calculate_sigmoid_price(
     base_price: U512,
     max_price: U512,
     precision: U512,
-) -> U512 {
+) -> Result<U512, BondingCurveError> {
     // Smoothstep formula: 3x^2 - 2x^3
     // sigmoid_progress = progress^2 * (3 - 2*progress)
-    let progress_squared = (progress * progress) / precision;
-    let three = U512::from(3u64);
-    let two = U512::from(2u64);
+    let progress_squared = div(mul(progress, progress)?, precision)?;
 
     // 3 - 2*progress (scaled by precision)
-    let three_scaled = three * precision;
-    let two_progress = two * progress;
-    let factor = if three_scaled > two_progress {
-        three_scaled - two_progress
-    } else {
-        U512::zero()
-    };
+    let three_scaled = mul(U512::from(3u64), precision)?;
+    let two_progress = mul(U512::from(2u64), progress)?;
+    let factor = three_scaled.saturating_sub(two_progress);
 
     // sigmoid_progress = progress^2 * factor / precision
-    let sigmoid_progress = (progress_squared * factor) / precision;
+    let sigmoid_progress = div(mul(progress_squared, factor)?, precision)?;
 
-    let price_range = max_price - base_price;
-    base_price + (sigmoid_progress * price_range) / precision
+    let price_range = sub(max_price, base_price)?;
+    add(base_price, div(mul(sigmoid_progress, price_range)?, precision)?)
 }
 
 /// Steep curve: Exponential-like growth (aggressive early rewards)
@@ -111,16 +267,77 @@ fn calculate_steep_price(
     base_price: U512,
     max_price: U512,
     precision: U512,
-) -> U512 {
+) -> Result<U512, BondingCurveError> {
     // Quadratic growth: progress^2
-    let steep_progress = (progress * progress) / precision;
+    let steep_progress = div(mul(progress, progress)?, precision)?;
+
+    let price_range = sub(max_price, base_price)?;
+    add(base_price, div(mul(steep_progress, price_range)?, precision)?)
+}
+
+// ============ Curve Integrals ============
+
+/// Numerator of the cumulative cost of buying the first `tokens_sold` tokens.
+///
+/// The cost of the curve segment `[a, b]` is `(N(b) - N(a)) / integral_denominator()`,
+/// which is the closed-form integral of the spot price over that range:
+///
+/// ```text
+/// ∫ price dx = base_price * x + price_range * total_supply * F(x / total_supply)
+/// ```
+///
+/// with `F(t) = t²/2` (Linear), `t³ - t⁴/2` (Sigmoid) and `t³/3` (Steep).
+/// Interpolated curves (Exponential, Logarithmic, Piecewise) use
+/// `total_supply * ∫ price dt` over their linear segments instead.
+/// Progress `t` is held with `INTEGRAL_SCALE` precision. Because every cost is a
+/// difference of the same monotone function, buying and selling is path
+/// independent and a round trip can never return more than it cost.
+fn cumulative_cost_numerator(
+    curve_type: CurveType,
+    tokens_sold: U256,
+    total_supply: U256,
+    base_price: U512,
+    max_price: U512,
+    points: &[ControlPoint],
+) -> Result<U512, BondingCurveError> {
+    let scale = U512::from(INTEGRAL_SCALE);
+    let two = U512::from(2u64);
+    let x = to_u512(tokens_sold);
+    let supply = to_u512(total_supply);
 
-    let price_range = max_price - base_price;
-    base_price + (steep_progress * price_range) / precision
+    // Progress scaled to [0, INTEGRAL_SCALE]
+    let t = div(mul(x, scale)?, supply)?;
+
+    if let CurveType::Exponential | CurveType::Logarithmic | CurveType::Piecewise = curve_type {
+        let (points, len) = control_points(curve_type, base_price, max_price, points)?;
+        return mul(supply, interpolated_area(&points[..len], t, scale)?);
+    }
+
+    let price_range = sub(max_price, base_price)?;
+
+    // F(t) scaled by INTEGRAL_SCALE
+    let area = match curve_type {
+        CurveType::Linear => div(mul(t, t)?, mul(two, scale)?)?,
+        CurveType::Sigmoid => {
+            let t_cubed = mul(mul(t, t)?, t)?;
+            let numerator = sub(mul(mul(two, t_cubed)?, scale)?, mul(t_cubed, t)?)?;
+            div(numerator, mul(mul(mul(two, scale)?, scale)?, scale)?)?
+        }
+        CurveType::Steep => div(mul(mul(t, t)?, t)?, mul(mul(U512::from(3u64), scale)?, scale)?)?,
+        _ => return Err(BondingCurveError::InvalidCurveType),
+    };
+
+    add(mul(mul(base_price, x)?, scale)?, mul(mul(price_range, supply)?, area)?)
+}
+
+/// Common denominator for `cumulative_cost_numerator` differences
+fn integral_denominator() -> U512 {
+    U512::from(INTEGRAL_SCALE) * U512::from(TOKEN_UNIT)
 }
 
 /// Calculate the number of tokens that can be bought with a given amount of CSPR
-/// Uses numerical integration (trapezoid rule) for accurate bonding curve math
+/// Inverts the exact curve integral by bisection, bracketed by the spot prices
+/// at either end of the purchase
 ///
 /// # Arguments
 /// * `curve_type` - The type of bonding curve
@@ -129,9 +346,10 @@ fn calculate_steep_price(
 /// * `total_supply` - Total supply for the curve
 /// * `base_price` - Starting price
 /// * `max_price` - Maximum price
+/// * `points` - Control points of a `Piecewise` curve (ignored otherwise)
 ///
 /// # Returns
-/// Number of tokens that can be purchased
+/// Largest number of tokens whose cost does not exceed `cspr_amount`
 pub fn calculate_tokens_for_cspr(
     curve_type: CurveType,
     cspr_amount: U512,
@@ -139,38 +357,75 @@ pub fn calculate_tokens_for_cspr(
     total_supply: U256,
     base_price: U512,
     max_price: U512,
-) -> U256 {
-    if cspr_amount.is_zero() || total_supply.is_zero() {
-        return U256::zero();
+    points: &[ControlPoint],
+) -> Result<U256, BondingCurveError> {
+    if cspr_amount.is_zero() || total_supply.is_zero() || current_sold >= total_supply {
+        return Ok(U256::zero());
     }
 
-    // For simplicity, use average price approximation
-    // More accurate: numerical integration with small steps
-
-    // Estimate tokens using current price as approximation
-    let current_price = calculate_price(curve_type, current_sold, total_supply, base_price, max_price);
+    let remaining = total_supply - current_sold;
+    let token_unit = U512::from(TOKEN_UNIT);
+    let cost = |tokens: U256| {
+        calculate_curve_integral(
+            curve_type,
+            current_sold,
+            current_sold + tokens,
+            total_supply,
+            base_price,
+            max_price,
+            points,
+        )
+    };
 
-    if current_price.is_zero() {
-        return U256::zero();
+    if cost(remaining)? <= cspr_amount {
+        return Ok(remaining);
     }
 
-    // tokens = cspr_amount / current_price (with precision for token decimals)
-    let token_decimals = U512::from(1_000_000_000_000_000_000u128); // 18 decimals
-    let tokens_raw = (cspr_amount * token_decimals) / current_price;
-
-    // Cap at remaining supply
-    let remaining = total_supply - current_sold;
-    let tokens = U256::from(tokens_raw.as_u128().min(remaining.as_u128()));
-
-    // Verify we don't exceed supply
-    if tokens > remaining {
+    // Price only rises along the curve, so the answer lies between the amount
+    // affordable at the final price and the amount affordable at the spot price
+    let spot_price =
+        calculate_price(curve_type, current_sold, total_supply, base_price, max_price, points)?;
+    let mut hi = if spot_price.is_zero() {
         remaining
     } else {
-        tokens
+        let at_spot = div(mul(cspr_amount, token_unit)?, spot_price)?;
+        if at_spot >= to_u512(remaining) {
+            remaining
+        } else {
+            to_u256(at_spot)? + U256::one()
+        }
+    };
+    let end_price =
+        calculate_price(curve_type, current_sold + hi, total_supply, base_price, max_price, points)?;
+    let mut lo = if end_price.is_zero() {
+        U256::zero()
+    } else {
+        let at_end = div(mul(cspr_amount, token_unit)?, end_price)?;
+        if at_end >= to_u512(hi) {
+            hi
+        } else {
+            to_u256(at_end)?
+        }
+    };
+
+    // Invariant: cost(lo) <= cspr_amount < cost(hi)
+    if cost(lo)? > cspr_amount {
+        lo = U256::zero();
+    }
+    while hi - lo > U256::one() {
+        let mid = lo + (hi - lo) / 2;
+        if cost(mid)? <= cspr_amount {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
     }
+
+    Ok(lo)
 }
 
 /// Calculate the CSPR received for selling tokens
+/// Exact curve integral over the sold range, rounded down
 ///
 /// # Arguments
 /// * `curve_type` - The type of bonding curve
@@ -179,6 +434,7 @@ pub fn calculate_tokens_for_cspr(
 /// * `total_supply` - Total supply for the curve
 /// * `base_price` - Starting price
 /// * `max_price` - Maximum price
+/// * `points` - Control points of a `Piecewise` curve (ignored otherwise)
 ///
 /// # Returns
 /// Amount of CSPR to receive (in motes)
@@ -189,30 +445,22 @@ pub fn calculate_cspr_for_tokens(
     total_supply: U256,
     base_price: U512,
     max_price: U512,
-) -> U512 {
-    if token_amount.is_zero() || current_sold.is_zero() {
-        return U512::zero();
+    points: &[ControlPoint],
+) -> Result<U512, BondingCurveError> {
+    if token_amount.is_zero() || current_sold.is_zero() || total_supply.is_zero() {
+        return Ok(U512::zero());
     }
 
-    // Calculate price at midpoint of sell range for average
-    let sell_from = if current_sold > token_amount {
-        current_sold - token_amount
-    } else {
-        U256::zero()
-    };
+    let sell_from = current_sold.saturating_sub(token_amount);
 
-    let avg_sold = (U256::from(current_sold.as_u128()) + U256::from(sell_from.as_u128())) / 2u128;
-    let avg_price = calculate_price(curve_type, avg_sold, total_supply, base_price, max_price);
+    let upper = cumulative_cost_numerator(curve_type, current_sold, total_supply, base_price, max_price, points)?;
+    let lower = cumulative_cost_numerator(curve_type, sell_from, total_supply, base_price, max_price, points)?;
 
-    // cspr = tokens * price / token_decimals
-    let token_decimals = U512::from(1_000_000_000_000_000_000u128);
-    let token_amount_u512 = U512::from(token_amount.as_u128());
-
-    (token_amount_u512 * avg_price) / token_decimals
+    div(sub(upper, lower)?, integral_denominator())
 }
 
-/// Calculate the integral (area under curve) for more accurate pricing
-/// Used for buy/sell operations to ensure correct token<->CSPR conversion
+/// Calculate the integral (area under curve) between two supply points
+/// This is the CSPR cost of buying `from_tokens..to_tokens`, rounded up
 pub fn calculate_curve_integral(
     curve_type: CurveType,
     from_tokens: U256,
@@ -220,39 +468,17 @@ pub fn calculate_curve_integral(
     total_supply: U256,
     base_price: U512,
     max_price: U512,
-) -> U512 {
+    points: &[ControlPoint],
+) -> Result<U512, BondingCurveError> {
     if from_tokens >= to_tokens || total_supply.is_zero() {
-        return U512::zero();
+        return Ok(U512::zero());
     }
 
-    let precision = U512::from(PRECISION);
-    let total_supply_u512 = U512::from(total_supply.as_u128());
-    let from_u512 = U512::from(from_tokens.as_u128());
-    let to_u512 = U512::from(to_tokens.as_u128());
-    let token_diff = to_u512 - from_u512;
-
-    // For linear curve, we can calculate exact integral:
-    // Integral from a to b of (base + (x/total)*range) dx
-    // = base*(b-a) + range/(2*total) * (b^2 - a^2)
-
-    match curve_type {
-        CurveType::Linear => {
-            let price_range = max_price - base_price;
-            let base_cost = base_price * token_diff;
-
-            // (b^2 - a^2) = (b-a)(b+a)
-            let sum_tokens = from_u512 + to_u512;
-            let range_cost = (price_range * token_diff * sum_tokens) / (U512::from(2u64) * total_supply_u512);
+    let upper = cumulative_cost_numerator(curve_type, to_tokens, total_supply, base_price, max_price, points)?;
+    let lower = cumulative_cost_numerator(curve_type, from_tokens, total_supply, base_price, max_price, points)?;
+    let denominator = integral_denominator();
 
-            (base_cost + range_cost) / U512::from(1_000_000_000_000_000_000u128) // Adjust for decimals
-        }
-        _ => {
-            // For non-linear curves, use midpoint approximation
-            let mid_tokens = (from_tokens + to_tokens) / 2u128;
-            let mid_price = calculate_price(curve_type, mid_tokens, total_supply, base_price, max_price);
-            (mid_price * token_diff) / U512::from(1_000_000_000_000_000_000u128)
-        }
-    }
+    div(add(sub(upper, lower)?, denominator - U512::one())?, denominator)
 }
 
 #[cfg(test)]
@@ -266,16 +492,210 @@ mod tests {
         let supply = U256::from(1_000_000u128);
 
         // At 0% progress, price should be base
-        let price_start = calculate_price(CurveType::Linear, U256::zero(), supply, base, max);
+        let price_start = calculate_price(CurveType::Linear, U256::zero(), supply, base, max, &[]).unwrap();
         assert_eq!(price_start, base);
 
         // At 100% progress, price should be max
-        let price_end = calculate_price(CurveType::Linear, supply, supply, base, max);
+        let price_end = calculate_price(CurveType::Linear, supply, supply, base, max, &[]).unwrap();
         assert_eq!(price_end, max);
 
         // At 50% progress, price should be midpoint
-        let price_mid = calculate_price(CurveType::Linear, supply / 2, supply, base, max);
+        let price_mid = calculate_price(CurveType::Linear, supply / 2, supply, base, max, &[]).unwrap();
         let expected_mid = (base + max) / 2;
         assert_eq!(price_mid, expected_mid);
     }
+
+    #[test]
+    fn test_linear_integral_matches_closed_form() {
+        let base = U512::from(1_000_000_000u64);
+        let max = U512::from(10_000_000_000u64);
+        let supply = U256::from(DEFAULT_TEST_SUPPLY);
+
+        // Buying the whole supply costs the average of base and max price per token
+        let cost = calculate_curve_integral(CurveType::Linear, U256::zero(), supply, supply, base, max, &[]).unwrap();
+        let tokens = U512::from(DEFAULT_TEST_SUPPLY / TOKEN_UNIT);
+        assert_eq!(cost, tokens * (base + max) / 2);
+    }
+
+    #[test]
+    fn test_buy_inverts_integral() {
+        let base = U512::from(1_000_000_000u64);
+        let max = U512::from(100_000_000_000u64);
+        let supply = U256::from(DEFAULT_TEST_SUPPLY);
+        let sold = supply / 3;
+        let cspr = U512::from(5_000_000_000_000u64);
+
+        for curve in ALL_CURVES {
+            let tokens = calculate_tokens_for_cspr(curve, cspr, sold, supply, base, max, &[]).unwrap();
+            let cost = calculate_curve_integral(curve, sold, sold + tokens, supply, base, max, &[]).unwrap();
+            let cost_one_more = calculate_curve_integral(curve, sold, sold + tokens + 1, supply, base, max, &[]).unwrap();
+            assert!(cost <= cspr);
+            assert!(cost_one_more > cspr);
+        }
+    }
+
+    /// Property: buying with any amount at any point on the curve and
+    /// immediately selling the tokens back never returns more CSPR than was spent
+    #[test]
+    fn test_round_trip_never_profits() {
+        let base = U512::from(1_000_000_000u64);
+        let max = U512::from(100_000_000_000u64);
+        let supply = U256::from(DEFAULT_TEST_SUPPLY);
+
+        // Deterministic pseudo-random inputs (64-bit LCG)
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        let mut next = || {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            seed
+        };
+
+        for curve in ALL_CURVES {
+            for _ in 0..200 {
+                let sold = U256::from(next() as u128 * next() as u128) % supply;
+                let cspr = U512::from(next() % 10_000_000_000_000_000u64 + 1);
+
+                let tokens = calculate_tokens_for_cspr(curve, cspr, sold, supply, base, max, &[]).unwrap();
+                let spent = calculate_curve_integral(curve, sold, sold + tokens, supply, base, max, &[]).unwrap();
+                let returned = calculate_cspr_for_tokens(curve, tokens, sold + tokens, supply, base, max, &[]).unwrap();
+
+                assert!(spent <= cspr);
+                assert!(returned <= spent);
+            }
+        }
+    }
+
+    #[test]
+    fn test_supply_beyond_u128() {
+        // 10^30 tokens with 18 decimals no longer fits in a u128
+        let supply = U256::from(DEFAULT_TEST_SUPPLY) * U256::from(1_000_000_000_000_000_000_000_000u128);
+        let base = U512::from(1u64);
+        let max = U512::from(1_000u64);
+
+        for curve in ALL_CURVES {
+            let price_end = calculate_price(curve, supply, supply, base, max, &[]).unwrap();
+            assert_eq!(price_end, max);
+
+            let sold = supply / 2;
+            let cspr = U512::from(1_000_000_000_000_000_000u128);
+            let tokens = calculate_tokens_for_cspr(curve, cspr, sold, supply, base, max, &[]).unwrap();
+            assert!(!tokens.is_zero());
+            let returned = calculate_cspr_for_tokens(curve, tokens, sold + tokens, supply, base, max, &[]).unwrap();
+            assert!(returned <= cspr);
+        }
+    }
+
+    #[test]
+    fn test_overflow_is_reported() {
+        let supply = U256::MAX;
+        let max = U512::MAX;
+
+        let result = calculate_curve_integral(CurveType::Steep, U256::zero(), supply, supply, U512::one(), max, &[]);
+        assert!(matches!(result, Err(BondingCurveError::Overflow)));
+    }
+
+    #[test]
+    fn test_shaped_curves_hit_endpoints() {
+        let base = U512::from(1_000_000_000u64);
+        let max = U512::from(100_000_000_000u64);
+        let supply = U256::from(DEFAULT_TEST_SUPPLY);
+
+        for curve in [CurveType::Exponential, CurveType::Logarithmic] {
+            assert_eq!(calculate_price(curve, U256::zero(), supply, base, max, &[]).unwrap(), base);
+            assert_eq!(calculate_price(curve, supply, supply, base, max, &[]).unwrap(), max);
+        }
+
+        // Exponential starts cheaper than linear, logarithmic dearer
+        let mid = supply / 2;
+        let linear = calculate_price(CurveType::Linear, mid, supply, base, max, &[]).unwrap();
+        assert!(calculate_price(CurveType::Exponential, mid, supply, base, max, &[]).unwrap() < linear);
+        assert!(calculate_price(CurveType::Logarithmic, mid, supply, base, max, &[]).unwrap() > linear);
+    }
+
+    #[test]
+    fn test_piecewise_two_points_matches_linear() {
+        let base = U512::from(1_000_000_000u64);
+        let max = U512::from(10_000_000_000u64);
+        let supply = U256::from(DEFAULT_TEST_SUPPLY);
+        let points = [(0u64, base), (10_000u64, max)];
+
+        for sold in [U256::zero(), supply / 3, supply / 2, supply] {
+            let linear = calculate_price(CurveType::Linear, sold, supply, base, max, &[]).unwrap();
+            let piecewise = calculate_price(CurveType::Piecewise, sold, supply, base, max, &points).unwrap();
+            assert_eq!(linear, piecewise);
+        }
+
+        let linear = calculate_curve_integral(CurveType::Linear, U256::zero(), supply, supply, base, max, &[]).unwrap();
+        let piecewise =
+            calculate_curve_integral(CurveType::Piecewise, U256::zero(), supply, supply, base, max, &points).unwrap();
+        assert_eq!(linear, piecewise);
+    }
+
+    #[test]
+    fn test_piecewise_round_trip_never_profits() {
+        let supply = U256::from(DEFAULT_TEST_SUPPLY);
+        let points = [
+            (0u64, U512::from(1_000_000_000u64)),
+            (2_500u64, U512::from(1_000_000_000u64)),
+            (6_000u64, U512::from(20_000_000_000u64)),
+            (10_000u64, U512::from(25_000_000_000u64)),
+        ];
+
+        let mut sold = U256::zero();
+        while sold < supply {
+            let cspr = U512::from(3_000_000_000_000u64);
+            let tokens =
+                calculate_tokens_for_cspr(CurveType::Piecewise, cspr, sold, supply, U512::zero(), U512::zero(), &points)
+                    .unwrap();
+            let spent = calculate_curve_integral(
+                CurveType::Piecewise,
+                sold,
+                sold + tokens,
+                supply,
+                U512::zero(),
+                U512::zero(),
+                &points,
+            )
+            .unwrap();
+            let returned = calculate_cspr_for_tokens(
+                CurveType::Piecewise,
+                tokens,
+                sold + tokens,
+                supply,
+                U512::zero(),
+                U512::zero(),
+                &points,
+            )
+            .unwrap();
+            assert!(spent <= cspr);
+            assert!(returned <= spent);
+            sold += tokens;
+        }
+    }
+
+    #[test]
+    fn test_control_point_validation() {
+        let price = U512::from(1_000u64);
+        assert!(validate_control_points(&[(0, price), (10_000, price)]).is_ok());
+        // Must span 0..=10000 bps
+        assert!(validate_control_points(&[(0, price), (9_000, price)]).is_err());
+        assert!(validate_control_points(&[(100, price), (10_000, price)]).is_err());
+        // Progress strictly increasing, prices non-decreasing
+        assert!(validate_control_points(&[(0, price), (5_000, price), (5_000, price), (10_000, price)]).is_err());
+        assert!(validate_control_points(&[(0, price * 2), (10_000, price)]).is_err());
+        // At most MAX_CONTROL_POINTS
+        let mut too_many = [(0u64, price); MAX_CONTROL_POINTS + 1];
+        for (i, point) in too_many.iter_mut().enumerate() {
+            point.0 = (i as u64 * 10_000) / MAX_CONTROL_POINTS as u64;
+        }
+        assert!(validate_control_points(&too_many).is_err());
+    }
+
+    const DEFAULT_TEST_SUPPLY: u128 = 1_000_000_000_000_000_000_000_000; // 1M tokens, 18 decimals
+    const ALL_CURVES: [CurveType; 5] = [
+        CurveType::Linear,
+        CurveType::Sigmoid,
+        CurveType::Steep,
+        CurveType::Exponential,
+        CurveType::Logarithmic,
+    ];
 }
@@ -1,5 +1,6 @@
 use casper_types::ApiError;
 
+#[derive(Debug)]
 #[repr(u16)]
 pub enum BondingCurveError {
     AlreadyInitialized = 1,
@@ -22,6 +23,30 @@ pub enum BondingCurveError {
     Overflow = 18,
     DivisionByZero = 19,
     LockedReentrancy = 20,
+    PairMismatch = 21,
+    LpLocked = 23,
+    InsufficientTokensOut = 24,
+    InsufficientCsprOut = 25,
+    TransactionExpired = 26,
+    WalletCapExceeded = 27,
+    HoldingCapExceeded = 28,
+    BuyCooldownActive = 29,
+    NotAllowlisted = 30,
+    PresaleCapExceeded = 31,
+    InvalidCurvePoints = 32,
+    NothingVested = 33,
+    InvalidCandleInterval = 34,
+    InvalidFeeSchedule = 35,
+    NoFeesToClaim = 36,
+    NotStarted = 37,
+    AlreadyStarted = 38,
+    InvalidStartTime = 39,
+    UnsupportedForQuoteToken = 40,
+    RefundWindowClosed = 41,
+    RefundWindowOpen = 42,
+    AllocationTooHigh = 43,
+    InvalidPromoRecipient = 44,
+    UnsupportedQuoteDecimals = 45,
 }
 
 impl From<BondingCurveError> for ApiError {
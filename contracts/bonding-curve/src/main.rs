@@ -7,21 +7,23 @@ mod curves;
 mod error;
 
 use alloc::boxed::Box;
+use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
+use alloc::vec::Vec;
 use casper_contract::{
-    contract_api::{runtime, storage, system},
+    contract_api::{account, runtime, storage, system},
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
     addressable_entity::{AddressableEntityHash, EntityEntryPoint as EntryPoint, EntryPoints},
     bytesrepr::{FromBytes, ToBytes},
-    contracts::NamedKeys,
+    contracts::{ContractHash, NamedKeys},
     runtime_args, CLType, CLTyped, CLValue, EntryPointAccess, EntryPointPayment,
     EntryPointType, Key, Parameter, RuntimeArgs, URef, U256, U512,
 };
 
-use curves::CurveType;
+use curves::{ControlPoint, CurveType};
 use error::BondingCurveError;
 
 // ============ Storage Keys ============
@@ -38,21 +40,101 @@ const TOKENS_SOLD: &str = "tokens_sold";
 const TOTAL_SUPPLY: &str = "total_supply";
 const BASE_PRICE: &str = "base_price";
 const MAX_PRICE: &str = "max_price";
+const CURVE_POINTS: &str = "curve_points";
 const STATUS: &str = "status";
 const PURCHASES: &str = "purchases";
+const EARLY_SPEND: &str = "early_spend";
+const HOLDINGS: &str = "holdings";
+const LAST_BUY: &str = "last_buy";
+const LAUNCH_TIME: &str = "launch_time";
+const SNIPE_WINDOW: &str = "snipe_window";
+const MAX_WALLET_CSPR: &str = "max_wallet_cspr";
+const MAX_HOLDING_BPS: &str = "max_holding_bps";
+const BUY_COOLDOWN: &str = "buy_cooldown";
+const PRESALE_SPEND: &str = "presale_spend";
+const TOTAL_PURCHASES: &str = "total_purchases";
+const CREATOR_ALLOCATION: &str = "creator_allocation";
+const VESTING_CLIFF: &str = "vesting_cliff";
+const VESTING_DURATION: &str = "vesting_duration";
+const VESTING_START: &str = "vesting_start";
+const VESTED_CLAIMED: &str = "vested_claimed";
+const TRADES: &str = "trades";
+const TRADE_COUNT: &str = "trade_count";
+const CANDLES: &str = "candles";
+const MERKLE_ROOT: &str = "merkle_root";
+const PUBLIC_START_TIME: &str = "public_start_time";
+const PRESALE_CAP: &str = "presale_cap";
 const PROMO_BUDGET: &str = "promo_budget";
 const PROMO_RELEASED: &str = "promo_released";
 const ACCUMULATED_FEES: &str = "accumulated_fees";
 const PLATFORM_WALLET: &str = "platform_wallet";
+const FEE_SCHEDULE: &str = "fee_schedule";
+const FEE_BALANCES: &str = "fee_balances";
+const CONTROLLER: &str = "controller";
 const DEX_FACTORY: &str = "dex_factory";
 const DEX_ROUTER: &str = "dex_router";
 const LOCKED: &str = "locked";
 const INITIALIZED: &str = "initialized";
+const CURVE_PURSE: &str = "curve_purse";
+const PROMO_PURSE: &str = "promo_purse";
+const FEE_BURN_PURSE: &str = "fee_burn_purse";
+const BURNED_FEES: &str = "burned_fees";
+const SELF_KEY: &str = "self_key";
+const WCSPR: &str = "wcspr";
+const QUOTE_TOKEN: &str = "quote_token";
+const REFUND_WINDOW: &str = "refund_window";
+const REFUND_END: &str = "refund_end";
+const REFUND_POOL: &str = "refund_pool";
+const REFUND_CLAIMED: &str = "refund_claimed";
+const LP_LOCK_DURATION: &str = "lp_lock_duration";
+const GRADUATION_PAIR: &str = "graduation_pair";
+const DEX_PAIR: &str = "dex_pair";
+const LP_AMOUNT: &str = "lp_amount";
+const LP_UNLOCK_TIME: &str = "lp_unlock_time";
 
 // Status values
 const STATUS_ACTIVE: u8 = 0;
 const STATUS_GRADUATED: u8 = 1;
 const STATUS_REFUNDING: u8 = 2;
+const STATUS_CANCELLED: u8 = 3;
+const STATUS_CLOSED: u8 = 4;
+
+// Refund claim window used when none is configured (90 days)
+const DEFAULT_REFUND_WINDOW: u64 = 90 * 24 * 60 * 60 * 1000;
+
+// Creator allocation cap used when no controller is configured
+const DEFAULT_MAX_CREATOR_ALLOCATION_BPS: u64 = 1000;
+
+/// LP tokens sent here are unrecoverable (same sink the pair uses for MINIMUM_LIQUIDITY)
+const LP_BURN_ADDRESS: Key = Key::Hash([0u8; 32]);
+
+/// Fee schedule recipient whose share is burned instead of credited: quote
+/// tokens go to the same sink as burned LP, CSPR to a purse nothing spends
+const FEE_BURN_ADDRESS: Key = LP_BURN_ADDRESS;
+
+/// Token amounts carry 18 decimals; curve prices are quoted per whole token
+const TOKEN_UNIT: u128 = 1_000_000_000_000_000_000u128;
+
+/// Decimals a quote token must have to share `TOKEN_UNIT` with the launch token
+const QUOTE_DECIMALS: u8 = 18;
+
+// Candle intervals in milliseconds
+const CANDLE_INTERVAL_MINUTE: u64 = 60_000;
+const CANDLE_INTERVAL_HOUR: u64 = 3_600_000;
+
+// Maximum number of fee recipients
+const MAX_FEE_RECIPIENTS: usize = 5;
+
+// Maximum trades or candle buckets returned by one paginated view
+const MAX_PAGE_SIZE: u64 = 100;
+
+/// Trade log entry: (account, (is_buy, tokens, cspr), (price, timestamp))
+/// `cspr` is what the trader paid or received; `price` is per whole token before fees
+type Trade = (Key, (bool, U256, U512), (U512, u64));
+
+/// OHLCV bucket: ((open, high, low), (close, cspr_volume, token_volume))
+/// `cspr_volume` sums the trade log's `cspr`, i.e. what traders paid or received
+type Candle = ((U512, U512, U512), (U512, U512, U256));
 
 // ============ Helper Functions ============
 
@@ -107,6 +189,29 @@ fn require_active() {
     }
 }
 
+/// Trading opens at the launch time, which may be scheduled in the future
+fn require_started() {
+    let launch_time: u64 = read_from_uref(LAUNCH_TIME);
+    if get_current_time() < launch_time {
+        runtime::revert(BondingCurveError::NotStarted);
+    }
+}
+
+/// Creator-only actions on a launch that has not opened yet
+fn require_creator_before_start() {
+    let caller = Key::Account(runtime::get_caller());
+    let creator: Key = read_from_uref(CREATOR);
+    if caller != creator {
+        runtime::revert(BondingCurveError::Unauthorized);
+    }
+
+    require_active();
+    let launch_time: u64 = read_from_uref(LAUNCH_TIME);
+    if get_current_time() >= launch_time {
+        runtime::revert(BondingCurveError::AlreadyStarted);
+    }
+}
+
 fn require_unlocked() {
     let locked: bool = read_from_uref(LOCKED);
     if locked {
@@ -122,9 +227,753 @@ fn unlock() {
     write_to_uref(LOCKED, false);
 }
 
-fn get_current_time() -> u64 {
-    // Use block time from runtime
-    runtime::get_blocktime().into()
+fn get_current_time() -> u64 {
+    // Use block time from runtime
+    runtime::get_blocktime().into()
+}
+
+fn read_account_entry<T: CLTyped + FromBytes + Default>(dictionary: &str, account_key: &str) -> T {
+    storage::dictionary_get(get_dictionary_uref(dictionary), account_key)
+        .unwrap_or_default()
+        .unwrap_or_default()
+}
+
+fn write_account_entry<T: CLTyped + ToBytes>(dictionary: &str, account_key: &str, value: T) {
+    storage::dictionary_put(get_dictionary_uref(dictionary), account_key, value);
+}
+
+/// Enforce the launch's anti-sniping limits for a buy and record it.
+/// Each limit is disabled when configured as zero.
+fn enforce_buy_limits(account_key: &str, cspr_paid: U512, tokens_bought: U256) {
+    let now = get_current_time();
+
+    // Per-account cooldown between buys
+    let cooldown: u64 = read_from_uref(BUY_COOLDOWN);
+    if cooldown > 0 {
+        let last_buy: Option<u64> = read_account_entry(LAST_BUY, account_key);
+        if let Some(last_buy) = last_buy {
+            if now < last_buy.saturating_add(cooldown) {
+                runtime::revert(BondingCurveError::BuyCooldownActive);
+            }
+        }
+        write_account_entry(LAST_BUY, account_key, Some(now));
+    }
+
+    // CSPR cap per wallet during the opening window
+    let max_wallet_cspr: U512 = read_from_uref(MAX_WALLET_CSPR);
+    let launch_time: u64 = read_from_uref(LAUNCH_TIME);
+    let snipe_window: u64 = read_from_uref(SNIPE_WINDOW);
+    if !max_wallet_cspr.is_zero() && now < launch_time.saturating_add(snipe_window) {
+        let spent: U512 = read_account_entry(EARLY_SPEND, account_key);
+        let new_spent = spent + cspr_paid;
+        if new_spent > max_wallet_cspr {
+            runtime::revert(BondingCurveError::WalletCapExceeded);
+        }
+        write_account_entry(EARLY_SPEND, account_key, new_spent);
+    }
+
+    // Maximum share of the curve supply bought by one account
+    let held: U256 = read_account_entry(HOLDINGS, account_key);
+    let new_held = held + tokens_bought;
+    let max_holding_bps: u64 = read_from_uref(MAX_HOLDING_BPS);
+    if max_holding_bps > 0 {
+        let total_supply: U256 = read_from_uref(TOTAL_SUPPLY);
+        let max_held = (total_supply * U256::from(max_holding_bps)) / U256::from(10000u64);
+        if new_held > max_held {
+            runtime::revert(BondingCurveError::HoldingCapExceeded);
+        }
+    }
+    write_account_entry(HOLDINGS, account_key, new_held);
+}
+
+/// Check a Merkle proof for `account`. Leaves are `blake2b(key_to_str(account))`
+/// and each level hashes the sorted pair of child nodes.
+fn verify_merkle_proof(root: [u8; 32], account: &Key, proof: &[[u8; 32]]) -> bool {
+    let mut node = runtime::blake2b(key_to_str(account).as_bytes());
+    for sibling in proof {
+        let mut pair = [0u8; 64];
+        let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+        pair[..32].copy_from_slice(&left);
+        pair[32..].copy_from_slice(&right);
+        node = runtime::blake2b(pair);
+    }
+    node == root
+}
+
+/// Before `public_start_time` only allowlisted accounts may buy, each up to
+/// the presale cap (zero means uncapped)
+fn enforce_presale(caller: &Key, account_key: &str, cspr_paid: U512, proof: Option<Vec<[u8; 32]>>) {
+    let public_start_time: u64 = read_from_uref(PUBLIC_START_TIME);
+    if get_current_time() >= public_start_time {
+        return;
+    }
+
+    let root: Option<[u8; 32]> = read_from_uref(MERKLE_ROOT);
+    let allowed = match (root, proof) {
+        (Some(root), Some(proof)) => verify_merkle_proof(root, caller, &proof),
+        _ => false,
+    };
+    if !allowed {
+        runtime::revert(BondingCurveError::NotAllowlisted);
+    }
+
+    let presale_cap: U512 = read_from_uref(PRESALE_CAP);
+    let spent: U512 = read_account_entry(PRESALE_SPEND, account_key);
+    let new_spent = spent + cspr_paid;
+    if !presale_cap.is_zero() && new_spent > presale_cap {
+        runtime::revert(BondingCurveError::PresaleCapExceeded);
+    }
+    write_account_entry(PRESALE_SPEND, account_key, new_spent);
+}
+
+/// Portion of an account's recorded purchase backing `tokens` of the `held`
+/// curve tokens it bought
+fn purchase_share(purchase: U512, tokens: U256, held: U256) -> U512 {
+    if held.is_zero() {
+        U512::zero()
+    } else if tokens >= held {
+        purchase
+    } else {
+        (purchase * curves::to_u512(tokens)) / curves::to_u512(held)
+    }
+}
+
+/// Revert if the caller-supplied deadline has passed
+fn require_not_expired(deadline: Option<u64>) {
+    if let Some(deadline) = deadline {
+        if get_current_time() > deadline {
+            runtime::revert(BondingCurveError::TransactionExpired);
+        }
+    }
+}
+
+// ============ Curve Purse ============
+
+fn get_curve_purse() -> URef {
+    runtime::get_key(CURVE_PURSE)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+/// CEP-18 token the curve is priced in; `None` for CSPR
+/// All U512 amounts (raised, fees, refunds) are in this token's base units when set.
+/// The token must treat a calling contract as `Key::Hash(contract hash)` for
+/// `transfer`/`transfer_from` (as the in-repo tokens do), since the curve holds
+/// the quote balance under `SELF_KEY` and pays sells, refunds and fees from it.
+/// Prices are per whole launch token (`TOKEN_UNIT`), so install only accepts
+/// quote tokens with the same 18 decimals (ECTO or WETH, not USDC or WBTC)
+fn get_quote_token() -> Option<Key> {
+    read_from_uref(QUOTE_TOKEN)
+}
+
+/// Balance backing the curve: the curve purse, or the quote token held by this contract
+fn curve_balance_internal() -> U512 {
+    match get_quote_token() {
+        Some(token) => curves::to_u512(call_token_balance_of(token, read_from_uref(SELF_KEY))),
+        None => system::get_purse_balance(get_curve_purse()).unwrap_or_default(),
+    }
+}
+
+/// Take payment into the curve: CSPR from the buyer's purse, or the quote
+/// token pulled with `transfer_from` against the buyer's allowance
+fn deposit_to_curve(payer: Key, source: Option<URef>, amount: U512) {
+    match get_quote_token() {
+        Some(token) => {
+            let amount = curves::to_u256(amount).unwrap_or_revert();
+            call_token_transfer_from(token, payer, read_from_uref(SELF_KEY), amount);
+        }
+        None => {
+            let source = source.unwrap_or_revert_with(BondingCurveError::InsufficientPayment);
+            system::transfer_from_purse_to_purse(source, get_curve_purse(), amount, None)
+                .unwrap_or_revert_with(BondingCurveError::TransferFailed);
+        }
+    }
+}
+
+/// Pay CSPR or the quote token out of the curve. CSPR can only be paid to
+/// accounts; any other recipient reverts rather than leaving funds behind.
+fn pay_from_curve(recipient: Key, amount: U512) {
+    if amount.is_zero() {
+        return;
+    }
+    if curve_balance_internal() < amount {
+        runtime::revert(BondingCurveError::InsufficientLiquidity);
+    }
+    if let Some(token) = get_quote_token() {
+        call_token_transfer(token, recipient, curves::to_u256(amount).unwrap_or_revert());
+    } else if let Key::Account(account) = recipient {
+        system::transfer_from_purse_to_account(get_curve_purse(), account, amount, None)
+            .unwrap_or_revert_with(BondingCurveError::TransferFailed);
+    } else {
+        runtime::revert(BondingCurveError::TransferFailed);
+    }
+}
+
+// ============ Fee Schedule ============
+
+/// Fee owed to each recipient on `amount`: (recipient, fee) per schedule entry
+fn fee_shares(amount: U512) -> Vec<(Key, U512)> {
+    let schedule: Vec<(Key, u64)> = read_from_uref(FEE_SCHEDULE);
+    schedule
+        .into_iter()
+        .map(|(recipient, bps)| (recipient, (amount * U512::from(bps)) / U512::from(10000u64)))
+        .collect()
+}
+
+fn total_fee_bps() -> u64 {
+    let schedule: Vec<(Key, u64)> = read_from_uref(FEE_SCHEDULE);
+    schedule.iter().map(|(_, bps)| bps).sum()
+}
+
+/// Credit each recipient's claimable balance; fees stay in the curve purse
+/// until claimed so a failing payout can never block a trade. The share of
+/// `FEE_BURN_ADDRESS` is burned right away instead
+fn accrue_fees(shares: &[(Key, U512)]) -> U512 {
+    let balances_uref = get_dictionary_uref(FEE_BALANCES);
+    let mut total = U512::zero();
+    for (recipient, fee) in shares {
+        if fee.is_zero() {
+            continue;
+        }
+        if *recipient == FEE_BURN_ADDRESS {
+            burn_fees(*fee);
+            continue;
+        }
+        let recipient_key = key_to_str(recipient);
+        let balance: U512 = storage::dictionary_get(balances_uref, &recipient_key)
+            .unwrap_or_default()
+            .unwrap_or_default();
+        storage::dictionary_put(balances_uref, &recipient_key, balance + *fee);
+        total += *fee;
+    }
+
+    let accumulated: U512 = read_from_uref(ACCUMULATED_FEES);
+    write_to_uref(ACCUMULATED_FEES, accumulated + total);
+    total
+}
+
+/// Move a burned fee share out of the curve for good
+fn burn_fees(amount: U512) {
+    match get_quote_token() {
+        Some(token) => {
+            call_token_transfer(token, FEE_BURN_ADDRESS, curves::to_u256(amount).unwrap_or_revert())
+        }
+        None => {
+            let burn_purse = runtime::get_key(FEE_BURN_PURSE)
+                .unwrap_or_revert()
+                .into_uref()
+                .unwrap_or_revert();
+            system::transfer_from_purse_to_purse(get_curve_purse(), burn_purse, amount, None)
+                .unwrap_or_revert_with(BondingCurveError::TransferFailed);
+        }
+    }
+    let burned: U512 = read_from_uref(BURNED_FEES);
+    write_to_uref(BURNED_FEES, burned + amount);
+}
+
+/// Pay out and clear a recipient's claimable fees
+fn claim_fees_for(recipient: Key) -> U512 {
+    let balances_uref = get_dictionary_uref(FEE_BALANCES);
+    let recipient_key = key_to_str(&recipient);
+    let balance: U512 = storage::dictionary_get(balances_uref, &recipient_key)
+        .unwrap_or_default()
+        .unwrap_or_default();
+    if balance.is_zero() {
+        return balance;
+    }
+
+    storage::dictionary_put(balances_uref, &recipient_key, U512::zero());
+    let accumulated: U512 = read_from_uref(ACCUMULATED_FEES);
+    write_to_uref(ACCUMULATED_FEES, accumulated.saturating_sub(balance));
+    pay_from_curve(recipient, balance);
+    balance
+}
+
+/// Reward the keeper who triggered a graduation or failed-launch finalization,
+/// out of the platform's accrued fees; the controller sets the bounty and the
+/// share of those fees it may take. Quote-token launches pay no bounty since
+/// it is denominated in motes, and neither does a controller that is not an
+/// addressable entity, as there is no contract to read the bounty from.
+fn pay_keeper_bounty(keeper: Key) -> U512 {
+    let controller: Key = read_from_uref(CONTROLLER);
+    let controller_contract = match (controller, get_quote_token()) {
+        (Key::AddressableEntity(entity_addr), None) => {
+            AddressableEntityHash::new(entity_addr.value())
+        }
+        _ => return U512::zero(),
+    };
+    let (bounty, cap_bps): (U512, u64) = runtime::call_contract(
+        controller_contract.into(),
+        "get_keeper_bounty",
+        runtime_args! {},
+    );
+
+    let platform_wallet: Key = read_from_uref(PLATFORM_WALLET);
+    let balances_uref = get_dictionary_uref(FEE_BALANCES);
+    let platform_key = key_to_str(&platform_wallet);
+    let platform_fees: U512 = storage::dictionary_get(balances_uref, &platform_key)
+        .unwrap_or_default()
+        .unwrap_or_default();
+
+    let reward = bounty.min((platform_fees * U512::from(cap_bps)) / U512::from(10000u64));
+    if reward.is_zero() {
+        return reward;
+    }
+
+    storage::dictionary_put(balances_uref, &platform_key, platform_fees - reward);
+    let accumulated: U512 = read_from_uref(ACCUMULATED_FEES);
+    write_to_uref(ACCUMULATED_FEES, accumulated.saturating_sub(reward));
+    pay_from_curve(keeper, reward);
+    reward
+}
+
+// ============ Promo Escrow ============
+
+fn get_promo_purse() -> URef {
+    runtime::get_key(PROMO_PURSE)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+fn promo_purse_balance_internal() -> U512 {
+    system::get_purse_balance(get_promo_purse()).unwrap_or_default()
+}
+
+/// Release unclaimed promo budget to the creator once the curve graduates.
+/// Install only accepts a promo budget from an account creator, so the
+/// release is never recorded without being paid
+fn return_promo_to_creator() {
+    let remaining = promo_purse_balance_internal();
+    if remaining.is_zero() {
+        return;
+    }
+    let creator = match read_from_uref::<Key>(CREATOR) {
+        Key::Account(account) => account,
+        _ => runtime::revert(BondingCurveError::InvalidPromoRecipient),
+    };
+
+    let promo_released: U512 = read_from_uref(PROMO_RELEASED);
+    write_to_uref(PROMO_RELEASED, promo_released + remaining);
+    system::transfer_from_purse_to_account(get_promo_purse(), creator, remaining, None)
+        .unwrap_or_revert_with(BondingCurveError::TransferFailed);
+}
+
+/// Move unclaimed promo budget into the refund pool of a failed launch
+fn forfeit_promo_to_refunds() {
+    let remaining = promo_purse_balance_internal();
+    if remaining.is_zero() {
+        return;
+    }
+    system::transfer_from_purse_to_purse(get_promo_purse(), get_curve_purse(), remaining, None)
+        .unwrap_or_revert_with(BondingCurveError::TransferFailed);
+    let cspr_raised: U512 = read_from_uref(CSPR_RAISED);
+    write_to_uref(CSPR_RAISED, cspr_raised + remaining);
+}
+
+// ============ Refunds ============
+
+/// Raise still owed to buyers: what is left of it in the curve, excluding unclaimed fees
+fn refundable_balance() -> U512 {
+    let cspr_raised: U512 = read_from_uref(CSPR_RAISED);
+    let accumulated: U512 = read_from_uref(ACCUMULATED_FEES);
+    cspr_raised.min(curve_balance_internal().saturating_sub(accumulated))
+}
+
+/// Move an active curve past its deadline into refunding and open the claim window;
+/// unreleased promo budget joins the refund pool
+fn finalize_failed_internal() {
+    let deadline: u64 = read_from_uref(DEADLINE);
+    let current_time = get_current_time();
+    if current_time < deadline {
+        runtime::revert(BondingCurveError::DeadlineNotReached);
+    }
+
+    write_to_uref(STATUS, STATUS_REFUNDING);
+    forfeit_promo_to_refunds();
+
+    let refund_window: u64 = read_from_uref(REFUND_WINDOW);
+    write_to_uref(REFUND_END, current_time + refund_window);
+    write_to_uref(REFUND_POOL, refundable_balance());
+}
+
+// ============ Token & DEX Calls ============
+
+/// Mint launch tokens through the token contract
+fn mint_tokens(to: Key, amount: U256) {
+    let token_hash: Key = read_from_uref(TOKEN_HASH);
+    if let Key::AddressableEntity(entity_addr) = token_hash {
+        let token_contract = AddressableEntityHash::new(entity_addr.value());
+        runtime::call_contract::<()>(
+            token_contract.into(),
+            "mint",
+            runtime_args! {
+                "to" => to,
+                "amount" => amount
+            },
+        );
+    }
+}
+
+/// Transfer launch tokens held by this contract; the token debits the
+/// calling contract, i.e. the balance minted to `SELF_KEY`
+fn transfer_tokens(recipient: Key, amount: U256) {
+    let token_hash: Key = read_from_uref(TOKEN_HASH);
+    if let Key::AddressableEntity(entity_addr) = token_hash {
+        let token_contract = AddressableEntityHash::new(entity_addr.value());
+        runtime::call_contract::<()>(
+            token_contract.into(),
+            "transfer",
+            runtime_args! {
+                "recipient" => recipient,
+                "amount" => amount
+            },
+        );
+    }
+}
+
+/// Burn launch tokens from an account through the token contract
+fn burn_tokens(from: Key, amount: U256) {
+    let token_hash: Key = read_from_uref(TOKEN_HASH);
+    if let Key::AddressableEntity(entity_addr) = token_hash {
+        let token_contract = AddressableEntityHash::new(entity_addr.value());
+        runtime::call_contract::<()>(
+            token_contract.into(),
+            "burn",
+            runtime_args! {
+                "from" => from,
+                "amount" => amount
+            },
+        );
+    }
+}
+
+/// The launch token as the DEX factory and pairs address it
+fn dex_token_key() -> Key {
+    dex_key(read_from_uref(TOKEN_HASH))
+}
+
+fn dex_key(token_hash: Key) -> Key {
+    match token_hash {
+        Key::AddressableEntity(entity_addr) => Key::Hash(entity_addr.value()),
+        other => other,
+    }
+}
+
+fn get_contract_hash(key: Key) -> ContractHash {
+    match key {
+        Key::Hash(hash) => ContractHash::new(hash),
+        _ => runtime::revert(BondingCurveError::PairMismatch),
+    }
+}
+
+fn call_factory_get_pair(factory: Key, token_a: Key, token_b: Key) -> Option<Key> {
+    runtime::call_contract(
+        get_contract_hash(factory),
+        "get_pair",
+        runtime_args! {
+            "token_a" => token_a,
+            "token_b" => token_b
+        },
+    )
+}
+
+fn call_factory_create_pair(factory: Key, token_a: Key, token_b: Key, pair: Key) {
+    runtime::call_contract::<Key>(
+        get_contract_hash(factory),
+        "create_pair",
+        runtime_args! {
+            "token_a" => token_a,
+            "token_b" => token_b,
+            "pair" => pair
+        },
+    );
+}
+
+fn call_pair_tokens(pair: Key) -> (Key, Key) {
+    let contract_hash = get_contract_hash(pair);
+    let token0: Key = runtime::call_contract(contract_hash, "token0", runtime_args! {});
+    let token1: Key = runtime::call_contract(contract_hash, "token1", runtime_args! {});
+    (token0, token1)
+}
+
+fn call_pair_get_reserves(pair: Key) -> (U256, U256, u64) {
+    runtime::call_contract(get_contract_hash(pair), "get_reserves", runtime_args! {})
+}
+
+fn call_pair_skim(pair: Key, to: Key) {
+    runtime::call_contract::<()>(
+        get_contract_hash(pair),
+        "skim",
+        runtime_args! {
+            "to" => to
+        },
+    );
+}
+
+fn call_pair_mint(pair: Key, to: Key) -> U256 {
+    runtime::call_contract(
+        get_contract_hash(pair),
+        "mint",
+        runtime_args! {
+            "to" => to
+        },
+    )
+}
+
+fn call_token_transfer(token: Key, recipient: Key, amount: U256) {
+    runtime::call_contract::<()>(
+        get_contract_hash(token),
+        "transfer",
+        runtime_args! {
+            "recipient" => recipient,
+            "amount" => amount
+        },
+    );
+}
+
+fn call_token_transfer_from(token: Key, owner: Key, recipient: Key, amount: U256) {
+    runtime::call_contract::<()>(
+        get_contract_hash(token),
+        "transfer_from",
+        runtime_args! {
+            "owner" => owner,
+            "recipient" => recipient,
+            "amount" => amount
+        },
+    );
+}
+
+fn call_token_balance_of(token: Key, owner: Key) -> U256 {
+    runtime::call_contract(
+        get_contract_hash(token),
+        "balance_of",
+        runtime_args! {
+            "owner" => owner
+        },
+    )
+}
+
+/// Wrap CSPR from the curve purse into WCSPR held by this contract
+fn wrap_cspr(wcspr: Key, amount: U512) {
+    let wrap_purse = system::create_purse();
+    system::transfer_from_purse_to_purse(get_curve_purse(), wrap_purse, amount, None)
+        .unwrap_or_revert_with(BondingCurveError::TransferFailed);
+    runtime::call_contract::<()>(
+        get_contract_hash(wcspr),
+        "deposit",
+        runtime_args! {
+            "amount" => amount,
+            "purse" => wrap_purse
+        },
+    );
+}
+
+// ============ Trade History ============
+
+fn candle_key(interval: u64, bucket_start: u64) -> String {
+    format!("{}_{}", interval, bucket_start)
+}
+
+/// Fold a trade into the candle bucket it falls in
+fn update_candle(interval: u64, timestamp: u64, price: U512, cspr: U512, tokens: U256) {
+    let candles_uref = get_dictionary_uref(CANDLES);
+    let key = candle_key(interval, timestamp - timestamp % interval);
+
+    let existing: Option<Candle> = storage::dictionary_get(candles_uref, &key).unwrap_or_default();
+    let candle = match existing {
+        Some(((open, high, low), (_, cspr_volume, token_volume))) => (
+            (open, high.max(price), low.min(price)),
+            (price, cspr_volume + cspr, token_volume + tokens),
+        ),
+        None => ((price, price, price), (price, cspr, tokens)),
+    };
+    storage::dictionary_put(candles_uref, &key, candle);
+}
+
+/// Append a trade to the log and roll it into the minute and hour candles.
+/// `cspr` is what the trader paid (fees included) or received (fees deducted)
+/// and is used for both the log entry and candle volume; `curve_cspr` is the
+/// fee-free curve amount and only sets the price
+fn record_trade(account: Key, is_buy: bool, tokens: U256, cspr: U512, curve_cspr: U512) {
+    let timestamp = get_current_time();
+    let price = (curve_cspr * U512::from(TOKEN_UNIT)) / curves::to_u512(tokens);
+
+    let index: u64 = read_from_uref(TRADE_COUNT);
+    let trade: Trade = (account, (is_buy, tokens, cspr), (price, timestamp));
+    storage::dictionary_put(get_dictionary_uref(TRADES), &index.to_string(), trade);
+    write_to_uref(TRADE_COUNT, index + 1);
+
+    update_candle(CANDLE_INTERVAL_MINUTE, timestamp, price, cspr, tokens);
+    update_candle(CANDLE_INTERVAL_HOUR, timestamp, price, cspr, tokens);
+}
+
+// ============ Creator Vesting ============
+
+/// Creator tokens vested at `now`: nothing before the cliff, then linear
+/// over the vesting duration, both measured from graduation
+fn vested_amount(now: u64) -> U256 {
+    let allocation: U256 = read_from_uref(CREATOR_ALLOCATION);
+    let start: u64 = read_from_uref(VESTING_START);
+    if allocation.is_zero() || start == 0 {
+        return U256::zero();
+    }
+
+    let elapsed = now.saturating_sub(start);
+    let cliff: u64 = read_from_uref(VESTING_CLIFF);
+    if elapsed < cliff {
+        return U256::zero();
+    }
+
+    let duration: u64 = read_from_uref(VESTING_DURATION);
+    if duration == 0 || elapsed >= duration {
+        return allocation;
+    }
+    (allocation * U256::from(elapsed)) / U256::from(duration)
+}
+
+// ============ Graduation ============
+
+/// Check the graduation setup at install so graduation cannot fail later:
+/// the pair must trade exactly this token against the quote (WCSPR for CSPR
+/// launches, which must answer as a token), and if the factory already lists
+/// a pair for them it must be this one. Registers the pair otherwise.
+fn register_graduation_pair(factory: Key, token: Key, quote: Key, pair: Key) {
+    call_token_balance_of(quote, pair);
+    let (token0, token1) = call_pair_tokens(pair);
+    let matches = (token0 == token && token1 == quote) || (token0 == quote && token1 == token);
+    if !matches {
+        runtime::revert(BondingCurveError::PairMismatch);
+    }
+
+    match call_factory_get_pair(factory, token, quote) {
+        Some(existing) if existing != pair => runtime::revert(BondingCurveError::PairMismatch),
+        Some(_) => {}
+        None => call_factory_create_pair(factory, token, quote, pair),
+    }
+}
+
+/// Whether the curve should graduate: it reached its threshold, or sold its
+/// whole supply short of it (buys are capped at whichever comes first)
+fn graduation_due(cspr_raised: U512, threshold: U512, tokens_sold: U256, total_supply: U256) -> bool {
+    cspr_raised >= threshold || tokens_sold >= total_supply
+}
+
+/// Token and quote amounts to add at graduation. An empty pool takes both in
+/// full at the curve price; a pool someone already seeded takes the most that
+/// fits its current ratio, so dust liquidity cannot block graduation.
+fn graduation_amounts(pair: Key, token: Key, tokens: U256, quote: U256) -> (U256, U256) {
+    let (reserve0, reserve1, _) = call_pair_get_reserves(pair);
+    let (token0, _) = call_pair_tokens(pair);
+    let (reserve_token, reserve_quote) = if token0 == token {
+        (reserve0, reserve1)
+    } else {
+        (reserve1, reserve0)
+    };
+    if reserve_token.is_zero() || reserve_quote.is_zero() {
+        return (tokens, quote);
+    }
+
+    let tokens_optimal = (quote * reserve_token) / reserve_quote;
+    if tokens_optimal <= tokens {
+        (tokens_optimal, quote)
+    } else {
+        (tokens, (tokens * reserve_quote) / reserve_token)
+    }
+}
+
+/// Move the raise into a token/WCSPR pool (token/quote for quote-token
+/// launches) at the curve's final price.
+/// Returns the pair and the LP amount, which is burned or time-locked here.
+fn graduate_internal() -> (Key, U256) {
+    write_to_uref(STATUS, STATUS_GRADUATED);
+
+    let pair: Key = read_from_uref(GRADUATION_PAIR);
+    let cspr_raised: U512 = read_from_uref(CSPR_RAISED);
+    let wcspr: Key = read_from_uref(WCSPR);
+    let quote = get_quote_token();
+    let pair_quote = quote.unwrap_or(wcspr);
+    let token = dex_token_key();
+
+    // Size the reserved allocation so the pool opens at the final spot price
+    let curve = CurveType::from_u8(read_from_uref(CURVE_TYPE)).unwrap_or_revert();
+    let tokens_sold: U256 = read_from_uref(TOKENS_SOLD);
+    let total_supply: U256 = read_from_uref(TOTAL_SUPPLY);
+    let base_price: U512 = read_from_uref(BASE_PRICE);
+    let max_price: U512 = read_from_uref(MAX_PRICE);
+    let curve_points: Vec<ControlPoint> = read_from_uref(CURVE_POINTS);
+    let final_price = curves::calculate_price(
+        curve,
+        tokens_sold,
+        total_supply,
+        base_price,
+        max_price,
+        &curve_points,
+    )
+    .unwrap_or_revert();
+    if final_price.is_zero() {
+        runtime::revert(BondingCurveError::DivisionByZero);
+    }
+    let liquidity_tokens = curves::to_u256((cspr_raised * U512::from(TOKEN_UNIT)) / final_price)
+        .unwrap_or_revert();
+    let liquidity_quote = curves::to_u256(cspr_raised).unwrap_or_revert();
+
+    // Registered at install; sweep donated balances so only real reserves price the pool
+    let platform_wallet: Key = read_from_uref(PLATFORM_WALLET);
+    call_pair_skim(pair, platform_wallet);
+    let (liquidity_tokens, liquidity_quote) =
+        graduation_amounts(pair, token, liquidity_tokens, liquidity_quote);
+
+    // Any raise a pre-seeded pool cannot absorb at its ratio stays claimable by the creator
+    let unused_quote = cspr_raised - curves::to_u512(liquidity_quote);
+    if !unused_quote.is_zero() {
+        let creator: Key = read_from_uref(CREATOR);
+        accrue_fees(&[(creator, unused_quote)]);
+    }
+
+    // Seed the pool: the raise (CSPR wrapped as WCSPR), tokens from the reserved allocation
+    if quote.is_none() {
+        wrap_cspr(wcspr, curves::to_u512(liquidity_quote));
+    }
+    call_token_transfer(pair_quote, pair, liquidity_quote);
+    mint_tokens(pair, liquidity_tokens);
+
+    let lock_duration: u64 = read_from_uref(LP_LOCK_DURATION);
+    let lp_holder = if lock_duration == 0 {
+        LP_BURN_ADDRESS
+    } else {
+        write_to_uref(LP_UNLOCK_TIME, get_current_time() + lock_duration);
+        read_from_uref(SELF_KEY)
+    };
+    let lp_amount = call_pair_mint(pair, lp_holder);
+
+    write_to_uref(DEX_PAIR, Some(pair));
+    write_to_uref(LP_AMOUNT, lp_amount);
+
+    return_promo_to_creator();
+
+    // Start the creator's vesting schedule; the allocation is held here
+    let allocation: U256 = read_from_uref(CREATOR_ALLOCATION);
+    if !allocation.is_zero() {
+        write_to_uref(VESTING_START, get_current_time());
+        mint_tokens(read_from_uref(SELF_KEY), allocation);
+    }
+
+    (pair, lp_amount)
+}
+
+/// Gross payment a buy actually takes: all of `offered`, or just enough that
+/// the post-fee amount covers `to_threshold` or `to_sell_out`, whichever is less
+fn accepted_payment(offered: U512, fee_bps: u64, to_threshold: U512, to_sell_out: U512) -> U512 {
+    let needed = to_threshold.min(to_sell_out);
+    let net_bps = U512::from(10000u64.saturating_sub(fee_bps));
+    if needed.is_zero() || net_bps.is_zero() {
+        return offered;
+    }
+    // Round the gross amount up so the net after fees never falls short
+    let gross = (needed * U512::from(10000u64) + net_bps - U512::one()) / net_bps;
+    offered.min(gross)
 }
 
 // ============ Entry Points ============
@@ -137,10 +986,54 @@ pub extern "C" fn init() {
         runtime::revert(BondingCurveError::AlreadyInitialized);
     }
 
+    // Key this contract is known by to tokens and pairs
+    let self_key: Key = runtime::get_named_arg("self_key");
+    runtime::put_key(SELF_KEY, storage::new_uref(self_key).into());
+
     // Create purchases dictionary
     storage::new_dictionary(PURCHASES)
         .unwrap_or_revert_with(casper_types::ApiError::User(100));
 
+    // Anti-sniping bookkeeping
+    storage::new_dictionary(EARLY_SPEND)
+        .unwrap_or_revert_with(casper_types::ApiError::User(100));
+    storage::new_dictionary(HOLDINGS)
+        .unwrap_or_revert_with(casper_types::ApiError::User(100));
+    storage::new_dictionary(LAST_BUY)
+        .unwrap_or_revert_with(casper_types::ApiError::User(100));
+
+    // Presale bookkeeping
+    storage::new_dictionary(PRESALE_SPEND)
+        .unwrap_or_revert_with(casper_types::ApiError::User(100));
+
+    // Claimable fees per recipient
+    storage::new_dictionary(FEE_BALANCES)
+        .unwrap_or_revert_with(casper_types::ApiError::User(100));
+
+    // Trade log and candles
+    storage::new_dictionary(TRADES)
+        .unwrap_or_revert_with(casper_types::ApiError::User(100));
+    storage::new_dictionary(CANDLES)
+        .unwrap_or_revert_with(casper_types::ApiError::User(100));
+
+    // Create the purse that holds all CSPR paid into the curve
+    let curve_purse = system::create_purse();
+    runtime::put_key(CURVE_PURSE, curve_purse.into());
+
+    // Burned CSPR fees end up here; no entry point ever spends from it
+    let fee_burn_purse = system::create_purse();
+    runtime::put_key(FEE_BURN_PURSE, fee_burn_purse.into());
+
+    // Escrow the creator's promo budget, funded at install
+    let promo_funding: URef = runtime::get_named_arg("promo_funding");
+    let promo_budget: U512 = read_from_uref(PROMO_BUDGET);
+    let promo_purse = system::create_purse();
+    if !promo_budget.is_zero() {
+        system::transfer_from_purse_to_purse(promo_funding, promo_purse, promo_budget, None)
+            .unwrap_or_revert_with(BondingCurveError::InsufficientPayment);
+    }
+    runtime::put_key(PROMO_PURSE, promo_purse.into());
+
     write_to_uref(INITIALIZED, true);
 }
 
@@ -186,6 +1079,13 @@ pub extern "C" fn tokens_sold() {
     runtime::ret(CLValue::from_t(sold).unwrap_or_revert());
 }
 
+/// Get the control points of a Piecewise curve (empty for other curve types)
+#[no_mangle]
+pub extern "C" fn curve_points() {
+    let points: Vec<ControlPoint> = read_from_uref(CURVE_POINTS);
+    runtime::ret(CLValue::from_t(points).unwrap_or_revert());
+}
+
 /// Get total supply
 #[no_mangle]
 pub extern "C" fn total_supply() {
@@ -200,6 +1100,95 @@ pub extern "C" fn status() {
     runtime::ret(CLValue::from_t(status).unwrap_or_revert());
 }
 
+/// Get the time trading opens
+#[no_mangle]
+pub extern "C" fn start_time() {
+    let launch_time: u64 = read_from_uref(LAUNCH_TIME);
+    runtime::ret(CLValue::from_t(launch_time).unwrap_or_revert());
+}
+
+/// Get the quote token, or `None` for CSPR-priced curves
+#[no_mangle]
+pub extern "C" fn quote_token() {
+    runtime::ret(CLValue::from_t(get_quote_token()).unwrap_or_revert());
+}
+
+/// Get the CSPR (or quote token) balance held by the curve
+#[no_mangle]
+pub extern "C" fn curve_purse_balance() {
+    let balance = curve_balance_internal();
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+/// Get the CSPR left in the promo escrow purse
+#[no_mangle]
+pub extern "C" fn promo_purse_balance() {
+    let balance = promo_purse_balance_internal();
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+/// Get the number of trades recorded
+#[no_mangle]
+pub extern "C" fn trade_count() {
+    let count: u64 = read_from_uref(TRADE_COUNT);
+    runtime::ret(CLValue::from_t(count).unwrap_or_revert());
+}
+
+/// Get trades (paginated, oldest first, at most 100 per call)
+#[no_mangle]
+pub extern "C" fn get_trades() {
+    let offset: u64 = runtime::get_named_arg("offset");
+    let limit: u64 = runtime::get_named_arg("limit");
+
+    let count: u64 = read_from_uref(TRADE_COUNT);
+    let trades_uref = get_dictionary_uref(TRADES);
+    let end = offset.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+    let mut result: Vec<Trade> = Vec::new();
+    let mut index = offset;
+    while index < end {
+        let trade: Option<Trade> =
+            storage::dictionary_get(trades_uref, &index.to_string()).unwrap_or_default();
+        if let Some(trade) = trade {
+            result.push(trade);
+        }
+        index += 1;
+    }
+
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+/// Get candles for `interval` (60000 or 3600000 ms) with bucket starts in [from, to]
+/// Returns (bucket_start, candle) pairs, skipping buckets without trades;
+/// scans at most 100 buckets per call
+#[no_mangle]
+pub extern "C" fn get_candles() {
+    let interval: u64 = runtime::get_named_arg("interval");
+    let from: u64 = runtime::get_named_arg("from");
+    let to: u64 = runtime::get_named_arg("to");
+
+    if interval != CANDLE_INTERVAL_MINUTE && interval != CANDLE_INTERVAL_HOUR {
+        runtime::revert(BondingCurveError::InvalidCandleInterval);
+    }
+
+    let candles_uref = get_dictionary_uref(CANDLES);
+    let mut result: Vec<(u64, Candle)> = Vec::new();
+    let mut bucket_start = from - from % interval;
+    let mut scanned = 0u64;
+    while bucket_start <= to && scanned < MAX_PAGE_SIZE {
+        let candle: Option<Candle> =
+            storage::dictionary_get(candles_uref, &candle_key(interval, bucket_start))
+                .unwrap_or_default();
+        if let Some(candle) = candle {
+            result.push((bucket_start, candle));
+        }
+        bucket_start += interval;
+        scanned += 1;
+    }
+
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
 /// Get current spot price
 #[no_mangle]
 pub extern "C" fn get_price() {
@@ -210,8 +1199,17 @@ pub extern "C" fn get_price() {
     let total_supply: U256 = read_from_uref(TOTAL_SUPPLY);
     let base_price: U512 = read_from_uref(BASE_PRICE);
     let max_price: U512 = read_from_uref(MAX_PRICE);
+    let curve_points: Vec<ControlPoint> = read_from_uref(CURVE_POINTS);
 
-    let price = curves::calculate_price(curve, tokens_sold, total_supply, base_price, max_price);
+    let price = curves::calculate_price(
+        curve,
+        tokens_sold,
+        total_supply,
+        base_price,
+        max_price,
+        &curve_points,
+    )
+    .unwrap_or_revert();
     runtime::ret(CLValue::from_t(price).unwrap_or_revert());
 }
 
@@ -279,12 +1277,12 @@ pub extern "C" fn get_quote_buy() {
     let total_supply: U256 = read_from_uref(TOTAL_SUPPLY);
     let base_price: U512 = read_from_uref(BASE_PRICE);
     let max_price: U512 = read_from_uref(MAX_PRICE);
+    let curve_points: Vec<ControlPoint> = read_from_uref(CURVE_POINTS);
 
-    // Deduct platform fee
-    let platform_fee_bps: u64 = read_from_uref(PLATFORM_FEE_BPS);
-    let creator_fee_bps: u64 = read_from_uref(CREATOR_FEE_BPS);
-    let total_fee_bps = platform_fee_bps + creator_fee_bps;
-    let fee = (cspr_amount * U512::from(total_fee_bps)) / U512::from(10000u64);
+    // Deduct fees
+    let fee = fee_shares(cspr_amount)
+        .iter()
+        .fold(U512::zero(), |total, (_, fee)| total + *fee);
     let cspr_after_fee = cspr_amount - fee;
 
     let tokens = curves::calculate_tokens_for_cspr(
@@ -294,7 +1292,9 @@ pub extern "C" fn get_quote_buy() {
         total_supply,
         base_price,
         max_price,
-    );
+        &curve_points,
+    )
+    .unwrap_or_revert();
 
     runtime::ret(CLValue::from_t(tokens).unwrap_or_revert());
 }
@@ -311,6 +1311,7 @@ pub extern "C" fn get_quote_sell() {
     let total_supply: U256 = read_from_uref(TOTAL_SUPPLY);
     let base_price: U512 = read_from_uref(BASE_PRICE);
     let max_price: U512 = read_from_uref(MAX_PRICE);
+    let curve_points: Vec<ControlPoint> = read_from_uref(CURVE_POINTS);
 
     let cspr_raw = curves::calculate_cspr_for_tokens(
         curve,
@@ -319,26 +1320,38 @@ pub extern "C" fn get_quote_sell() {
         total_supply,
         base_price,
         max_price,
-    );
+        &curve_points,
+    )
+    .unwrap_or_revert();
 
     // Deduct fees
-    let platform_fee_bps: u64 = read_from_uref(PLATFORM_FEE_BPS);
-    let creator_fee_bps: u64 = read_from_uref(CREATOR_FEE_BPS);
-    let total_fee_bps = platform_fee_bps + creator_fee_bps;
-    let fee = (cspr_raw * U512::from(total_fee_bps)) / U512::from(10000u64);
+    let fee = fee_shares(cspr_raw)
+        .iter()
+        .fold(U512::zero(), |total, (_, fee)| total + *fee);
     let cspr_after_fee = cspr_raw - fee;
 
     runtime::ret(CLValue::from_t(cspr_after_fee).unwrap_or_revert());
 }
 
-/// Buy tokens with CSPR
+/// Buy tokens with CSPR (or the quote token)
+/// Up to `amount` motes are moved from the caller-supplied `purse` into the curve purse
+/// (quote-token curves instead pull `amount` with `transfer_from` and take no purse);
+/// a buy that reaches the graduation threshold takes only what it needs and graduates,
+/// paying its buyer the keeper bounty (nothing for quote-token launches or when the
+/// controller is not an addressable entity)
 #[no_mangle]
 pub extern "C" fn buy() {
     require_active();
+    require_started();
     require_unlocked();
     lock();
 
     let cspr_amount: U512 = runtime::get_named_arg("amount");
+    let source_purse: Option<URef> = runtime::get_named_arg("purse");
+    let min_tokens_out: U256 = runtime::get_named_arg("min_tokens_out");
+    let deadline: Option<u64> = runtime::get_named_arg("deadline");
+    let merkle_proof: Option<Vec<[u8; 32]>> = runtime::get_named_arg("merkle_proof");
+    require_not_expired(deadline);
     if cspr_amount.is_zero() {
         unlock();
         runtime::revert(BondingCurveError::InvalidAmount);
@@ -346,15 +1359,6 @@ pub extern "C" fn buy() {
 
     let caller = Key::Account(runtime::get_caller());
 
-    // Calculate fees
-    let platform_fee_bps: u64 = read_from_uref(PLATFORM_FEE_BPS);
-    let creator_fee_bps: u64 = read_from_uref(CREATOR_FEE_BPS);
-
-    let platform_fee = (cspr_amount * U512::from(platform_fee_bps)) / U512::from(10000u64);
-    let creator_fee = (cspr_amount * U512::from(creator_fee_bps)) / U512::from(10000u64);
-    let cspr_for_curve = cspr_amount - platform_fee - creator_fee;
-
-    // Calculate tokens to receive
     let curve_type_val: u8 = read_from_uref(CURVE_TYPE);
     let curve = CurveType::from_u8(curve_type_val).unwrap_or_revert();
 
@@ -362,7 +1366,34 @@ pub extern "C" fn buy() {
     let total_supply: U256 = read_from_uref(TOTAL_SUPPLY);
     let base_price: U512 = read_from_uref(BASE_PRICE);
     let max_price: U512 = read_from_uref(MAX_PRICE);
+    let curve_points: Vec<ControlPoint> = read_from_uref(CURVE_POINTS);
+    let cspr_raised: U512 = read_from_uref(CSPR_RAISED);
+    let graduation_threshold: U512 = read_from_uref(GRADUATION_THRESHOLD);
+
+    // Only accept what is needed to reach the threshold or buy out the supply;
+    // the rest of `amount` is never taken from the buyer's purse
+    let cspr_paid = accepted_payment(
+        cspr_amount,
+        total_fee_bps(),
+        graduation_threshold.saturating_sub(cspr_raised),
+        curves::calculate_curve_integral(
+            curve,
+            tokens_sold,
+            total_supply,
+            total_supply,
+            base_price,
+            max_price,
+            &curve_points,
+        )
+        .unwrap_or_revert(),
+    );
+
+    // Calculate fees
+    let fees = fee_shares(cspr_paid);
+    let total_fee = fees.iter().fold(U512::zero(), |total, (_, fee)| total + *fee);
+    let cspr_for_curve = cspr_paid - total_fee;
 
+    // Calculate tokens to receive
     let tokens_to_buy = curves::calculate_tokens_for_cspr(
         curve,
         cspr_for_curve,
@@ -370,13 +1401,20 @@ pub extern "C" fn buy() {
         total_supply,
         base_price,
         max_price,
-    );
+        &curve_points,
+    )
+    .unwrap_or_revert();
 
     if tokens_to_buy.is_zero() {
         unlock();
         runtime::revert(BondingCurveError::InvalidAmount);
     }
 
+    if tokens_to_buy < min_tokens_out {
+        unlock();
+        runtime::revert(BondingCurveError::InsufficientTokensOut);
+    }
+
     // Check remaining supply
     let remaining = total_supply - tokens_sold;
     if tokens_to_buy > remaining {
@@ -384,56 +1422,47 @@ pub extern "C" fn buy() {
         runtime::revert(BondingCurveError::InsufficientLiquidity);
     }
 
+    let caller_key = key_to_str(&caller);
+    enforce_presale(&caller, &caller_key, cspr_paid, merkle_proof);
+    enforce_buy_limits(&caller_key, cspr_paid, tokens_to_buy);
+
+    // Take payment into the curve purse
+    deposit_to_curve(caller, source_purse, cspr_paid);
+
     // Update state
     let new_tokens_sold = tokens_sold + tokens_to_buy;
     write_to_uref(TOKENS_SOLD, new_tokens_sold);
 
-    let cspr_raised: U512 = read_from_uref(CSPR_RAISED);
-    write_to_uref(CSPR_RAISED, cspr_raised + cspr_for_curve);
+    let new_cspr_raised = cspr_raised + cspr_for_curve;
+    write_to_uref(CSPR_RAISED, new_cspr_raised);
 
-    // Accumulate creator fees
-    let accumulated: U512 = read_from_uref(ACCUMULATED_FEES);
-    write_to_uref(ACCUMULATED_FEES, accumulated + creator_fee);
+    // Credit fee recipients
+    accrue_fees(&fees);
 
     // Record purchase for potential refund
     let purchases_uref = get_dictionary_uref(PURCHASES);
-    let caller_key = key_to_str(&caller);
     let existing: U512 = storage::dictionary_get(purchases_uref, &caller_key)
         .unwrap_or_default()
         .unwrap_or(U512::zero());
     storage::dictionary_put(purchases_uref, &caller_key, existing + cspr_for_curve);
-
-    // Transfer platform fee to platform wallet
-    if !platform_fee.is_zero() {
-        let platform_wallet: Key = read_from_uref(PLATFORM_WALLET);
-        if let Key::Account(account) = platform_wallet {
-            system::transfer_to_account(account, platform_fee, None).unwrap_or_revert();
-        }
-    }
+    let total_purchases: U512 = read_from_uref(TOTAL_PURCHASES);
+    write_to_uref(TOTAL_PURCHASES, total_purchases + cspr_for_curve);
 
     // Mint tokens to buyer via token contract
-    let token_hash: Key = read_from_uref(TOKEN_HASH);
-    if let Key::AddressableEntity(entity_addr) = token_hash {
-        let token_contract = AddressableEntityHash::new(entity_addr.value());
-        runtime::call_contract::<()>(
-            token_contract.into(),
-            "mint",
-            runtime_args! {
-                "to" => caller,
-                "amount" => tokens_to_buy
-            },
-        );
-    }
+    mint_tokens(caller, tokens_to_buy);
 
-    unlock();
+    record_trade(caller, true, tokens_to_buy, cspr_paid, cspr_for_curve);
 
-    // Check if should graduate
-    let graduation_threshold: U512 = read_from_uref(GRADUATION_THRESHOLD);
-    let new_cspr_raised: U512 = read_from_uref(CSPR_RAISED);
-    if new_cspr_raised >= graduation_threshold {
-        // Auto-graduate could be triggered here or left for manual call
+    // The buy that crosses the threshold or sells out the supply graduates the
+    // curve, closing trading, and earns its buyer the keeper bounty like any
+    // other graduation trigger
+    if graduation_due(new_cspr_raised, graduation_threshold, new_tokens_sold, total_supply) {
+        graduate_internal();
+        pay_keeper_bounty(caller);
     }
 
+    unlock();
+
     runtime::ret(CLValue::from_t(tokens_to_buy).unwrap_or_revert());
 }
 
@@ -441,10 +1470,14 @@ pub extern "C" fn buy() {
 #[no_mangle]
 pub extern "C" fn sell() {
     require_active();
+    require_started();
     require_unlocked();
     lock();
 
     let token_amount: U256 = runtime::get_named_arg("amount");
+    let min_cspr_out: U512 = runtime::get_named_arg("min_cspr_out");
+    let deadline: Option<u64> = runtime::get_named_arg("deadline");
+    require_not_expired(deadline);
     if token_amount.is_zero() {
         unlock();
         runtime::revert(BondingCurveError::InvalidAmount);
@@ -460,6 +1493,7 @@ pub extern "C" fn sell() {
     let total_supply: U256 = read_from_uref(TOTAL_SUPPLY);
     let base_price: U512 = read_from_uref(BASE_PRICE);
     let max_price: U512 = read_from_uref(MAX_PRICE);
+    let curve_points: Vec<ControlPoint> = read_from_uref(CURVE_POINTS);
 
     if token_amount > tokens_sold {
         unlock();
@@ -473,115 +1507,196 @@ pub extern "C" fn sell() {
         total_supply,
         base_price,
         max_price,
-    );
+        &curve_points,
+    )
+    .unwrap_or_revert();
 
     // Deduct fees
-    let platform_fee_bps: u64 = read_from_uref(PLATFORM_FEE_BPS);
-    let creator_fee_bps: u64 = read_from_uref(CREATOR_FEE_BPS);
-
-    let platform_fee = (cspr_raw * U512::from(platform_fee_bps)) / U512::from(10000u64);
-    let creator_fee = (cspr_raw * U512::from(creator_fee_bps)) / U512::from(10000u64);
-    let cspr_to_return = cspr_raw - platform_fee - creator_fee;
+    let fees = fee_shares(cspr_raw);
+    let total_fee = fees.iter().fold(U512::zero(), |total, (_, fee)| total + *fee);
+    let cspr_to_return = cspr_raw - total_fee;
 
-    // Check curve has enough CSPR
-    let cspr_raised: U512 = read_from_uref(CSPR_RAISED);
-    if cspr_to_return > cspr_raised {
+    if cspr_to_return < min_cspr_out {
         unlock();
-        runtime::revert(BondingCurveError::InsufficientLiquidity);
+        runtime::revert(BondingCurveError::InsufficientCsprOut);
     }
 
+    // Check the curve's raise covers the payout and the fees taken from it
+    let cspr_raised: U512 = read_from_uref(CSPR_RAISED);
+    let new_cspr_raised = match cspr_raised.checked_sub(cspr_raw) {
+        Some(remaining) => remaining,
+        None => {
+            unlock();
+            runtime::revert(BondingCurveError::InsufficientLiquidity);
+        }
+    };
+
     // Burn tokens from seller via token contract
-    let token_hash: Key = read_from_uref(TOKEN_HASH);
-    if let Key::AddressableEntity(entity_addr) = token_hash {
-        let token_contract = AddressableEntityHash::new(entity_addr.value());
-        runtime::call_contract::<()>(
-            token_contract.into(),
-            "burn",
-            runtime_args! {
-                "from" => caller,
-                "amount" => token_amount
-            },
-        );
-    }
+    burn_tokens(caller, token_amount);
+
+    // Sold tokens no longer count towards the holding cap or back a refund
+    let caller_key = key_to_str(&caller);
+    let held: U256 = read_account_entry(HOLDINGS, &caller_key);
+    let purchase: U512 = read_account_entry(PURCHASES, &caller_key);
+    let sold_share = purchase_share(purchase, token_amount, held);
+    write_account_entry(HOLDINGS, &caller_key, held.saturating_sub(token_amount));
+    write_account_entry(PURCHASES, &caller_key, purchase - sold_share);
+    let total_purchases: U512 = read_from_uref(TOTAL_PURCHASES);
+    write_to_uref(TOTAL_PURCHASES, total_purchases.saturating_sub(sold_share));
 
     // Update state
     write_to_uref(TOKENS_SOLD, tokens_sold - token_amount);
-    write_to_uref(CSPR_RAISED, cspr_raised - cspr_raw);
+    write_to_uref(CSPR_RAISED, new_cspr_raised);
 
-    // Accumulate creator fees
-    let accumulated: U512 = read_from_uref(ACCUMULATED_FEES);
-    write_to_uref(ACCUMULATED_FEES, accumulated + creator_fee);
+    // Credit fee recipients
+    accrue_fees(&fees);
 
     // Transfer CSPR to seller
-    if let Key::Account(account) = caller {
-        system::transfer_to_account(account, cspr_to_return, None).unwrap_or_revert();
-    }
+    pay_from_curve(caller, cspr_to_return);
 
-    // Transfer platform fee
-    if !platform_fee.is_zero() {
-        let platform_wallet: Key = read_from_uref(PLATFORM_WALLET);
-        if let Key::Account(account) = platform_wallet {
-            system::transfer_to_account(account, platform_fee, None).unwrap_or_revert();
-        }
-    }
+    record_trade(caller, false, token_amount, cspr_to_return, cspr_raw);
 
     unlock();
     runtime::ret(CLValue::from_t(cspr_to_return).unwrap_or_revert());
 }
 
 /// Claim refund if deadline passed and not graduated
+/// Burns `token_amount` of the caller's curve-bought tokens (all of them when
+/// unset) and refunds the matching share of their purchase, paid pro rata from
+/// the refund pool (remaining raised CSPR plus any forfeited promo budget).
 #[no_mangle]
 pub extern "C" fn claim_refund() {
     require_unlocked();
     lock();
 
+    let token_amount: Option<U256> = runtime::get_named_arg("token_amount");
+
+    // The first claim after the deadline finalizes the launch if nobody has yet
     let status: u8 = read_from_uref(STATUS);
-    if status == STATUS_GRADUATED {
+    if status == STATUS_ACTIVE {
+        finalize_failed_internal();
+    } else if status != STATUS_REFUNDING {
         unlock();
         runtime::revert(BondingCurveError::RefundNotAvailable);
     }
 
-    // Check deadline
-    let deadline: u64 = read_from_uref(DEADLINE);
-    let current_time = get_current_time();
-
-    if current_time < deadline {
+    let refund_end: u64 = read_from_uref(REFUND_END);
+    if get_current_time() >= refund_end {
         unlock();
-        runtime::revert(BondingCurveError::DeadlineNotReached);
-    }
-
-    // Set status to refunding if not already
-    if status == STATUS_ACTIVE {
-        write_to_uref(STATUS, STATUS_REFUNDING);
+        runtime::revert(BondingCurveError::RefundWindowClosed);
     }
 
     let caller = Key::Account(runtime::get_caller());
     let caller_key = key_to_str(&caller);
 
-    // Get caller's purchase amount
-    let purchases_uref = get_dictionary_uref(PURCHASES);
-    let purchase_amount: U512 = storage::dictionary_get(purchases_uref, &caller_key)
-        .unwrap_or_default()
-        .unwrap_or(U512::zero());
+    // Get caller's purchase amount and the tokens it bought
+    let purchase_amount: U512 = read_account_entry(PURCHASES, &caller_key);
+    let held: U256 = read_account_entry(HOLDINGS, &caller_key);
+    let tokens_returned = token_amount.unwrap_or(held);
 
-    if purchase_amount.is_zero() {
+    if purchase_amount.is_zero() || held.is_zero() || tokens_returned.is_zero() {
         unlock();
         runtime::revert(BondingCurveError::NoRefundAvailable);
     }
+    if tokens_returned > held {
+        unlock();
+        runtime::revert(BondingCurveError::InsufficientTokens);
+    }
+
+    let claimed = purchase_share(purchase_amount, tokens_returned, held);
+
+    // Refunds are paid out of the raised CSPR actually left in the purse
+    let cspr_raised: U512 = read_from_uref(CSPR_RAISED);
+    let available = refundable_balance();
+    let total_purchases: U512 = read_from_uref(TOTAL_PURCHASES);
+    let refund = if total_purchases.is_zero() {
+        U512::zero()
+    } else {
+        (claimed * available) / total_purchases
+    };
 
-    // Clear purchase record
-    storage::dictionary_put(purchases_uref, &caller_key, U512::zero());
+    // Return the tokens and settle the records
+    burn_tokens(caller, tokens_returned);
+    write_account_entry(HOLDINGS, &caller_key, held - tokens_returned);
+    write_account_entry(PURCHASES, &caller_key, purchase_amount - claimed);
+    write_to_uref(TOTAL_PURCHASES, total_purchases.saturating_sub(claimed));
+    write_to_uref(CSPR_RAISED, cspr_raised.saturating_sub(refund));
+    let refund_claimed: U512 = read_from_uref(REFUND_CLAIMED);
+    write_to_uref(REFUND_CLAIMED, refund_claimed + refund);
 
     // Transfer refund
-    if let Key::Account(account) = caller {
-        system::transfer_to_account(account, purchase_amount, None).unwrap_or_revert();
+    pay_from_curve(caller, refund);
+
+    unlock();
+    runtime::ret(CLValue::from_t(refund).unwrap_or_revert());
+}
+
+/// Finalize a launch that missed its deadline, opening the refund claim window
+/// Returns the keeper bounty paid to the caller, which is zero for quote-token
+/// launches and when the controller is not an addressable entity
+#[no_mangle]
+pub extern "C" fn finalize_failed() {
+    require_active();
+    require_unlocked();
+    lock();
+
+    finalize_failed_internal();
+    let bounty = pay_keeper_bounty(Key::Account(runtime::get_caller()));
+
+    unlock();
+    runtime::ret(CLValue::from_t(bounty).unwrap_or_revert());
+}
+
+/// Platform sends refunds left unclaimed after the claim window to its wallet
+/// and closes the curve
+#[no_mangle]
+pub extern "C" fn sweep_unclaimed() {
+    require_unlocked();
+    lock();
+
+    let caller = Key::Account(runtime::get_caller());
+    let platform_wallet: Key = read_from_uref(PLATFORM_WALLET);
+    if caller != platform_wallet {
+        unlock();
+        runtime::revert(BondingCurveError::Unauthorized);
     }
 
+    let status: u8 = read_from_uref(STATUS);
+    if status != STATUS_REFUNDING {
+        unlock();
+        runtime::revert(BondingCurveError::RefundNotAvailable);
+    }
+    let refund_end: u64 = read_from_uref(REFUND_END);
+    if get_current_time() < refund_end {
+        unlock();
+        runtime::revert(BondingCurveError::RefundWindowOpen);
+    }
+
+    // Unclaimed fees stay claimable by their recipients
+    let unclaimed = refundable_balance();
+    write_to_uref(CSPR_RAISED, U512::zero());
+    write_to_uref(STATUS, STATUS_CLOSED);
+    pay_from_curve(platform_wallet, unclaimed);
+
     unlock();
-    runtime::ret(CLValue::from_t(purchase_amount).unwrap_or_revert());
+    runtime::ret(CLValue::from_t(unclaimed).unwrap_or_revert());
 }
 
-/// Graduate the curve to DEX (creates pair and adds liquidity)
+/// Get refund status as (total_refundable, claimed, refund_end)
+/// All zero until the launch is finalized as failed
+#[no_mangle]
+pub extern "C" fn refund_status() {
+    let refund_pool: U512 = read_from_uref(REFUND_POOL);
+    let refund_claimed: U512 = read_from_uref(REFUND_CLAIMED);
+    let refund_end: u64 = read_from_uref(REFUND_END);
+    runtime::ret(CLValue::from_t((refund_pool, refund_claimed, refund_end)).unwrap_or_revert());
+}
+
+/// Graduate the curve to DEX (adds liquidity to the pair registered at install)
+/// The buy that crosses the threshold or sells out the supply already graduates
+/// the curve and pays its buyer the keeper bounty. This is the retry path for a
+/// curve left active past that point: it only succeeds once graduation is due
+/// and pays the caller the same bounty, if any
 #[no_mangle]
 pub extern "C" fn graduate() {
     require_active();
@@ -590,24 +1705,84 @@ pub extern "C" fn graduate() {
 
     let cspr_raised: U512 = read_from_uref(CSPR_RAISED);
     let graduation_threshold: U512 = read_from_uref(GRADUATION_THRESHOLD);
+    let tokens_sold: U256 = read_from_uref(TOKENS_SOLD);
+    let total_supply: U256 = read_from_uref(TOTAL_SUPPLY);
 
-    if cspr_raised < graduation_threshold {
+    if !graduation_due(cspr_raised, graduation_threshold, tokens_sold, total_supply) {
         unlock();
         runtime::revert(BondingCurveError::GraduationThresholdNotMet);
     }
 
-    // Mark as graduated
-    write_to_uref(STATUS, STATUS_GRADUATED);
-
-    // TODO: Integrate with DEX to create pair and add liquidity
-    // This would call the DEX factory to create a pair
-    // and the router to add liquidity
+    let result = graduate_internal();
+    pay_keeper_bounty(Key::Account(runtime::get_caller()));
 
     unlock();
-    runtime::ret(CLValue::from_t(true).unwrap_or_revert());
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+/// Get anti-sniping settings as (max_wallet_cspr, (snipe_window_end, max_holding_bps, buy_cooldown))
+#[no_mangle]
+pub extern "C" fn get_buy_limits() {
+    let max_wallet_cspr: U512 = read_from_uref(MAX_WALLET_CSPR);
+    let launch_time: u64 = read_from_uref(LAUNCH_TIME);
+    let snipe_window: u64 = read_from_uref(SNIPE_WINDOW);
+    let max_holding_bps: u64 = read_from_uref(MAX_HOLDING_BPS);
+    let buy_cooldown: u64 = read_from_uref(BUY_COOLDOWN);
+    let result = (
+        max_wallet_cspr,
+        (launch_time.saturating_add(snipe_window), max_holding_bps, buy_cooldown),
+    );
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+/// Get presale settings as (merkle_root, public_start_time, presale_cap)
+#[no_mangle]
+pub extern "C" fn get_presale_info() {
+    let root: Option<[u8; 32]> = read_from_uref(MERKLE_ROOT);
+    let public_start_time: u64 = read_from_uref(PUBLIC_START_TIME);
+    let presale_cap: U512 = read_from_uref(PRESALE_CAP);
+    runtime::ret(CLValue::from_t((root, public_start_time, presale_cap)).unwrap_or_revert());
+}
+
+/// Get the DEX pair, LP amount and LP unlock time (0 when the LP was burned)
+#[no_mangle]
+pub extern "C" fn get_graduation_info() {
+    let pair: Option<Key> = read_from_uref(DEX_PAIR);
+    let lp_amount: U256 = read_from_uref(LP_AMOUNT);
+    let unlock_time: u64 = read_from_uref(LP_UNLOCK_TIME);
+    runtime::ret(CLValue::from_t((pair, lp_amount, unlock_time)).unwrap_or_revert());
+}
+
+/// Creator withdraws time-locked LP tokens once the lock has expired
+#[no_mangle]
+pub extern "C" fn withdraw_lp() {
+    let caller = Key::Account(runtime::get_caller());
+    let creator: Key = read_from_uref(CREATOR);
+
+    if caller != creator {
+        runtime::revert(BondingCurveError::Unauthorized);
+    }
+
+    let lock_duration: u64 = read_from_uref(LP_LOCK_DURATION);
+    let unlock_time: u64 = read_from_uref(LP_UNLOCK_TIME);
+    if lock_duration == 0 || get_current_time() < unlock_time {
+        runtime::revert(BondingCurveError::LpLocked);
+    }
+
+    let pair: Option<Key> = read_from_uref(DEX_PAIR);
+    let pair = pair.unwrap_or_revert_with(BondingCurveError::LpLocked);
+    let lp_amount: U256 = read_from_uref(LP_AMOUNT);
+    if lp_amount.is_zero() {
+        runtime::revert(BondingCurveError::InvalidAmount);
+    }
+
+    write_to_uref(LP_AMOUNT, U256::zero());
+    call_token_transfer(pair, creator, lp_amount);
+
+    runtime::ret(CLValue::from_t(lp_amount).unwrap_or_revert());
 }
 
-/// Creator withdraws accumulated fees
+/// Creator withdraws their accumulated fees
 #[no_mangle]
 pub extern "C" fn withdraw_fees() {
     let caller = Key::Account(runtime::get_caller());
@@ -617,18 +1792,136 @@ pub extern "C" fn withdraw_fees() {
         runtime::revert(BondingCurveError::Unauthorized);
     }
 
-    let accumulated: U512 = read_from_uref(ACCUMULATED_FEES);
-    if accumulated.is_zero() {
+    let claimed = claim_fees_for(caller);
+    if claimed.is_zero() {
         runtime::revert(BondingCurveError::NoPromoToWithdraw);
     }
 
-    write_to_uref(ACCUMULATED_FEES, U512::zero());
+    runtime::ret(CLValue::from_t(claimed).unwrap_or_revert());
+}
+
+/// Any fee recipient withdraws their claimable fees
+#[no_mangle]
+pub extern "C" fn claim_fees() {
+    let caller = Key::Account(runtime::get_caller());
+
+    let claimed = claim_fees_for(caller);
+    if claimed.is_zero() {
+        runtime::revert(BondingCurveError::NoFeesToClaim);
+    }
+
+    runtime::ret(CLValue::from_t(claimed).unwrap_or_revert());
+}
+
+/// Get the fee schedule as (recipient, bps) entries
+#[no_mangle]
+pub extern "C" fn fee_schedule() {
+    let schedule: Vec<(Key, u64)> = read_from_uref(FEE_SCHEDULE);
+    runtime::ret(CLValue::from_t(schedule).unwrap_or_revert());
+}
+
+/// Get the total fees burned through the fee schedule's burn recipient
+#[no_mangle]
+pub extern "C" fn burned_fees() {
+    let burned: U512 = read_from_uref(BURNED_FEES);
+    runtime::ret(CLValue::from_t(burned).unwrap_or_revert());
+}
+
+/// Get a recipient's claimable fees
+#[no_mangle]
+pub extern "C" fn claimable_fees() {
+    let recipient: Key = runtime::get_named_arg("recipient");
+    let balances_uref = get_dictionary_uref(FEE_BALANCES);
+    let balance: U512 = storage::dictionary_get(balances_uref, &key_to_str(&recipient))
+        .unwrap_or_default()
+        .unwrap_or_default();
+    runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
+}
+
+/// Creator claims vested tokens from their allocation
+#[no_mangle]
+pub extern "C" fn claim_vested() {
+    let caller = Key::Account(runtime::get_caller());
+    let creator: Key = read_from_uref(CREATOR);
+
+    if caller != creator {
+        runtime::revert(BondingCurveError::Unauthorized);
+    }
+
+    let claimed: U256 = read_from_uref(VESTED_CLAIMED);
+    let claimable = vested_amount(get_current_time()).saturating_sub(claimed);
+    if claimable.is_zero() {
+        runtime::revert(BondingCurveError::NothingVested);
+    }
+
+    write_to_uref(VESTED_CLAIMED, claimed + claimable);
+    transfer_tokens(creator, claimable);
+
+    runtime::ret(CLValue::from_t(claimable).unwrap_or_revert());
+}
+
+/// Get vesting status as (allocation, claimed, (claimable, cliff_end, vesting_end))
+/// Times are zero until the curve graduates
+#[no_mangle]
+pub extern "C" fn vesting_status() {
+    let allocation: U256 = read_from_uref(CREATOR_ALLOCATION);
+    let claimed: U256 = read_from_uref(VESTED_CLAIMED);
+    let start: u64 = read_from_uref(VESTING_START);
+    let cliff: u64 = read_from_uref(VESTING_CLIFF);
+    let duration: u64 = read_from_uref(VESTING_DURATION);
+
+    let claimable = vested_amount(get_current_time()).saturating_sub(claimed);
+    let (cliff_end, vesting_end) = if start == 0 {
+        (0u64, 0u64)
+    } else {
+        (start + cliff, start + duration.max(cliff))
+    };
+
+    runtime::ret(
+        CLValue::from_t((allocation, claimed, (claimable, cliff_end, vesting_end)))
+            .unwrap_or_revert(),
+    );
+}
+
+/// Creator moves a scheduled launch to a new start time
+/// The deadline and presale end move with it
+#[no_mangle]
+pub extern "C" fn reschedule() {
+    require_creator_before_start();
+
+    let new_start: u64 = runtime::get_named_arg("start_time");
+    if new_start < get_current_time() {
+        runtime::revert(BondingCurveError::InvalidStartTime);
+    }
+
+    let launch_time: u64 = read_from_uref(LAUNCH_TIME);
+    let deadline: u64 = read_from_uref(DEADLINE);
+    let public_start_time: u64 = read_from_uref(PUBLIC_START_TIME);
+    let shift = |time: u64| {
+        if new_start >= launch_time {
+            time + (new_start - launch_time)
+        } else {
+            time.saturating_sub(launch_time - new_start)
+        }
+    };
 
-    if let Key::Account(account) = caller {
-        system::transfer_to_account(account, accumulated, None).unwrap_or_revert();
+    let new_deadline = shift(deadline);
+    if new_deadline <= new_start {
+        runtime::revert(BondingCurveError::InvalidStartTime);
     }
 
-    runtime::ret(CLValue::from_t(accumulated).unwrap_or_revert());
+    write_to_uref(DEADLINE, new_deadline);
+    write_to_uref(PUBLIC_START_TIME, shift(public_start_time));
+    write_to_uref(LAUNCH_TIME, new_start);
+}
+
+/// Creator cancels a scheduled launch before it opens; the promo escrow is returned
+#[no_mangle]
+pub extern "C" fn cancel() {
+    require_creator_before_start();
+
+    write_to_uref(STATUS, STATUS_CANCELLED);
+    return_promo_to_creator();
 }
 
 /// Creator claims promo budget based on milestones
@@ -641,6 +1934,13 @@ pub extern "C" fn claim_promo_milestone() {
         runtime::revert(BondingCurveError::Unauthorized);
     }
 
+    // A launch past its deadline without graduating owes its promo to refunds
+    let status: u8 = read_from_uref(STATUS);
+    let deadline: u64 = read_from_uref(DEADLINE);
+    if status != STATUS_ACTIVE || get_current_time() >= deadline {
+        runtime::revert(BondingCurveError::CurveNotActive);
+    }
+
     let promo_budget: U512 = read_from_uref(PROMO_BUDGET);
     let promo_released: U512 = read_from_uref(PROMO_RELEASED);
     let cspr_raised: U512 = read_from_uref(CSPR_RAISED);
@@ -684,8 +1984,13 @@ pub extern "C" fn claim_promo_milestone() {
 
     write_to_uref(PROMO_RELEASED, promo_released + claimable);
 
+    // Promo payouts come only from the creator-funded escrow
+    if promo_purse_balance_internal() < claimable {
+        runtime::revert(BondingCurveError::InsufficientLiquidity);
+    }
     if let Key::Account(account) = caller {
-        system::transfer_to_account(account, claimable, None).unwrap_or_revert();
+        system::transfer_from_purse_to_account(get_promo_purse(), account, claimable, None)
+            .unwrap_or_revert_with(BondingCurveError::TransferFailed);
     }
 
     runtime::ret(CLValue::from_t(claimable).unwrap_or_revert());
@@ -699,7 +2004,10 @@ fn get_entry_points() -> EntryPoints {
     // Init
     entry_points.add_entry_point(EntryPoint::new(
         "init",
-        vec![],
+        vec![
+            Parameter::new("self_key", CLType::Key),
+            Parameter::new("promo_funding", CLType::URef),
+        ],
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Called,
@@ -734,6 +2042,18 @@ fn get_entry_points() -> EntryPoints {
         EntryPointPayment::Caller,
     ));
 
+    entry_points.add_entry_point(EntryPoint::new(
+        "curve_points",
+        vec![],
+        CLType::List(Box::new(CLType::Tuple2([
+            Box::new(CLType::U64),
+            Box::new(CLType::U512),
+        ]))),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
     entry_points.add_entry_point(EntryPoint::new(
         "graduation_threshold",
         vec![],
@@ -779,6 +2099,42 @@ fn get_entry_points() -> EntryPoints {
         EntryPointPayment::Caller,
     ));
 
+    entry_points.add_entry_point(EntryPoint::new(
+        "start_time",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "quote_token",
+        vec![],
+        CLType::Option(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "curve_purse_balance",
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "promo_purse_balance",
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
     entry_points.add_entry_point(EntryPoint::new(
         "get_price",
         vec![],
@@ -835,7 +2191,16 @@ fn get_entry_points() -> EntryPoints {
     // State-changing entry points
     entry_points.add_entry_point(EntryPoint::new(
         "buy",
-        vec![Parameter::new("amount", CLType::U512)],
+        vec![
+            Parameter::new("amount", CLType::U512),
+            Parameter::new("purse", CLType::Option(Box::new(CLType::URef))),
+            Parameter::new("min_tokens_out", CLType::U256),
+            Parameter::new("deadline", CLType::Option(Box::new(CLType::U64))),
+            Parameter::new(
+                "merkle_proof",
+                CLType::Option(Box::new(CLType::List(Box::new(CLType::ByteArray(32))))),
+            ),
+        ],
         CLType::U256,
         EntryPointAccess::Public,
         EntryPointType::Called,
@@ -844,7 +2209,11 @@ fn get_entry_points() -> EntryPoints {
 
     entry_points.add_entry_point(EntryPoint::new(
         "sell",
-        vec![Parameter::new("amount", CLType::U256)],
+        vec![
+            Parameter::new("amount", CLType::U256),
+            Parameter::new("min_cspr_out", CLType::U512),
+            Parameter::new("deadline", CLType::Option(Box::new(CLType::U64))),
+        ],
         CLType::U512,
         EntryPointAccess::Public,
         EntryPointType::Called,
@@ -853,7 +2222,7 @@ fn get_entry_points() -> EntryPoints {
 
     entry_points.add_entry_point(EntryPoint::new(
         "claim_refund",
-        vec![],
+        vec![Parameter::new("token_amount", CLType::Option(Box::new(CLType::U256)))],
         CLType::U512,
         EntryPointAccess::Public,
         EntryPointType::Called,
@@ -863,7 +2232,58 @@ fn get_entry_points() -> EntryPoints {
     entry_points.add_entry_point(EntryPoint::new(
         "graduate",
         vec![],
-        CLType::Bool,
+        CLType::Tuple2([Box::new(CLType::Key), Box::new(CLType::U256)]),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_buy_limits",
+        vec![],
+        CLType::Tuple2([
+            Box::new(CLType::U512),
+            Box::new(CLType::Tuple3([
+                Box::new(CLType::U64),
+                Box::new(CLType::U64),
+                Box::new(CLType::U64),
+            ])),
+        ]),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_presale_info",
+        vec![],
+        CLType::Tuple3([
+            Box::new(CLType::Option(Box::new(CLType::ByteArray(32)))),
+            Box::new(CLType::U64),
+            Box::new(CLType::U512),
+        ]),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_graduation_info",
+        vec![],
+        CLType::Tuple3([
+            Box::new(CLType::Option(Box::new(CLType::Key))),
+            Box::new(CLType::U256),
+            Box::new(CLType::U64),
+        ]),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "withdraw_lp",
+        vec![],
+        CLType::U256,
         EntryPointAccess::Public,
         EntryPointType::Called,
         EntryPointPayment::Caller,
@@ -878,6 +2298,176 @@ fn get_entry_points() -> EntryPoints {
         EntryPointPayment::Caller,
     ));
 
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_fees",
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "fee_schedule",
+        vec![],
+        CLType::List(Box::new(CLType::Tuple2([
+            Box::new(CLType::Key),
+            Box::new(CLType::U64),
+        ]))),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "burned_fees",
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "claimable_fees",
+        vec![Parameter::new("recipient", CLType::Key)],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "trade_count",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_trades",
+        vec![
+            Parameter::new("offset", CLType::U64),
+            Parameter::new("limit", CLType::U64),
+        ],
+        CLType::List(Box::new(CLType::Tuple3([
+            Box::new(CLType::Key),
+            Box::new(CLType::Tuple3([
+                Box::new(CLType::Bool),
+                Box::new(CLType::U256),
+                Box::new(CLType::U512),
+            ])),
+            Box::new(CLType::Tuple2([Box::new(CLType::U512), Box::new(CLType::U64)])),
+        ]))),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_candles",
+        vec![
+            Parameter::new("interval", CLType::U64),
+            Parameter::new("from", CLType::U64),
+            Parameter::new("to", CLType::U64),
+        ],
+        CLType::List(Box::new(CLType::Tuple2([
+            Box::new(CLType::U64),
+            Box::new(CLType::Tuple2([
+                Box::new(CLType::Tuple3([
+                    Box::new(CLType::U512),
+                    Box::new(CLType::U512),
+                    Box::new(CLType::U512),
+                ])),
+                Box::new(CLType::Tuple3([
+                    Box::new(CLType::U512),
+                    Box::new(CLType::U512),
+                    Box::new(CLType::U256),
+                ])),
+            ])),
+        ]))),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "claim_vested",
+        vec![],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "vesting_status",
+        vec![],
+        CLType::Tuple3([
+            Box::new(CLType::U256),
+            Box::new(CLType::U256),
+            Box::new(CLType::Tuple3([
+                Box::new(CLType::U256),
+                Box::new(CLType::U64),
+                Box::new(CLType::U64),
+            ])),
+        ]),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "reschedule",
+        vec![Parameter::new("start_time", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel",
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "finalize_failed",
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "sweep_unclaimed",
+        vec![],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "refund_status",
+        vec![],
+        CLType::Tuple3([
+            Box::new(CLType::U512),
+            Box::new(CLType::U512),
+            Box::new(CLType::U64),
+        ]),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
     entry_points.add_entry_point(EntryPoint::new(
         "claim_promo_milestone",
         vec![],
@@ -900,12 +2490,135 @@ pub extern "C" fn call() {
     let graduation_threshold: U512 = runtime::get_named_arg("graduation_threshold");
     let platform_fee_bps: u64 = runtime::get_named_arg("platform_fee_bps");
     let creator_fee_bps: u64 = runtime::get_named_arg("creator_fee_bps");
+    // Trading opens at `start_time` (0 for immediately) and must open before the deadline
+    let start_time: u64 = runtime::get_named_arg("start_time");
     let deadline: u64 = runtime::get_named_arg("deadline");
+    let launch_time = start_time.max(get_current_time());
+    if deadline <= launch_time {
+        runtime::revert(BondingCurveError::InvalidStartTime);
+    }
     let total_supply: U256 = runtime::get_named_arg("total_supply");
     let base_price: U512 = runtime::get_named_arg("base_price");
     let max_price: U512 = runtime::get_named_arg("max_price");
+    // Control points of a Piecewise curve; empty for the built-in shapes
+    let curve_points: Vec<ControlPoint> = runtime::get_named_arg("curve_points");
     let promo_budget: U512 = runtime::get_named_arg("promo_budget");
+    // Up to 5 (recipient account, bps) fee entries, e.g. referrer or staking pool,
+    // plus `FEE_BURN_ADDRESS` to burn a share; defaults to the platform and creator fees
+    let fee_schedule: Vec<(Key, u64)> = runtime::get_named_arg("fee_schedule");
+    // Creator's own first buy, made before the curve is visible to anyone else.
+    // It goes through `buy`, so it is only possible for a launch that opens now
+    let initial_buy_cspr: U512 = runtime::get_named_arg("initial_buy_cspr");
+    if !initial_buy_cspr.is_zero() && launch_time > get_current_time() {
+        runtime::revert(BondingCurveError::InvalidStartTime);
+    }
+    // Allowlist proof for the dev buy when the launch opens with a presale
+    let initial_buy_proof: Option<Vec<[u8; 32]>> = runtime::get_named_arg("initial_buy_proof");
+    if !initial_buy_cspr.is_zero() && Key::Account(runtime::get_caller()) != creator {
+        runtime::revert(BondingCurveError::Unauthorized);
+    }
     let platform_wallet: Key = runtime::get_named_arg("platform_wallet");
+    // Launchpad controller holding the keeper bounty settings
+    let controller: Key = runtime::get_named_arg("controller");
+    let dex_factory: Key = runtime::get_named_arg("dex_factory");
+    let graduation_pair: Key = runtime::get_named_arg("graduation_pair");
+    let wcspr: Key = runtime::get_named_arg("wcspr");
+    // CEP-18 token to price the curve in instead of CSPR; the promo escrow is
+    // CSPR-only, so quote-token launches run without one
+    let quote_token: Option<Key> = runtime::get_named_arg("quote_token");
+    if quote_token.is_some() && !promo_budget.is_zero() {
+        runtime::revert(BondingCurveError::UnsupportedForQuoteToken);
+    }
+    if let Some(token) = quote_token {
+        let decimals: u8 = runtime::call_contract(get_contract_hash(token), "decimals", runtime_args! {});
+        if decimals != QUOTE_DECIMALS {
+            runtime::revert(BondingCurveError::UnsupportedQuoteDecimals);
+        }
+    }
+    register_graduation_pair(
+        dex_factory,
+        dex_key(token_hash),
+        quote_token.unwrap_or(wcspr),
+        graduation_pair,
+    );
+    // Promo escrow is paid out in CSPR, which only an account can receive
+    if !promo_budget.is_zero() && !matches!(creator, Key::Account(_)) {
+        runtime::revert(BondingCurveError::InvalidPromoRecipient);
+    }
+    let lp_lock_duration: u64 = runtime::get_named_arg("lp_lock_duration");
+    // How long refunds stay claimable after a failed launch is finalized (0 for 90 days)
+    let refund_window: u64 = match runtime::get_named_arg("refund_window") {
+        0 => DEFAULT_REFUND_WINDOW,
+        window => window,
+    };
+
+    let fee_schedule = if fee_schedule.is_empty() {
+        let mut defaults = Vec::new();
+        if platform_fee_bps > 0 {
+            defaults.push((platform_wallet, platform_fee_bps));
+        }
+        if creator_fee_bps > 0 {
+            defaults.push((creator, creator_fee_bps));
+        }
+        defaults
+    } else {
+        fee_schedule
+    };
+    let scheduled_fee_bps: u64 = fee_schedule.iter().map(|(_, bps)| bps).sum();
+    // Recipients claim as `Key::Account(caller)`, so only accounts can ever be
+    // paid; the burn address is the one other recipient, and its share is burned
+    let valid_recipient = |recipient: &Key| matches!(recipient, Key::Account(_)) || *recipient == FEE_BURN_ADDRESS;
+    if fee_schedule.len() > MAX_FEE_RECIPIENTS
+        || fee_schedule.iter().any(|(recipient, bps)| *bps == 0 || !valid_recipient(recipient))
+        || scheduled_fee_bps >= 10000
+    {
+        runtime::revert(BondingCurveError::InvalidFeeSchedule);
+    }
+
+    // Anti-sniping limits (durations in milliseconds, zero disables)
+    let snipe_window: u64 = runtime::get_named_arg("snipe_window");
+    let max_wallet_cspr: U512 = runtime::get_named_arg("max_wallet_cspr");
+    let max_holding_bps: u64 = runtime::get_named_arg("max_holding_bps");
+    let buy_cooldown: u64 = runtime::get_named_arg("buy_cooldown");
+    if max_holding_bps > 10000 {
+        runtime::revert(BondingCurveError::InvalidAmount);
+    }
+
+    // Optional allowlisted presale before `public_start_time`
+    let merkle_root: Option<[u8; 32]> = runtime::get_named_arg("merkle_root");
+    let public_start_time: u64 = runtime::get_named_arg("public_start_time");
+    let presale_cap: U512 = runtime::get_named_arg("presale_cap");
+
+    // Creator allocation (bps of curve supply) vested from graduation, in milliseconds
+    let creator_allocation_bps: u64 = runtime::get_named_arg("creator_allocation_bps");
+    let vesting_cliff: u64 = runtime::get_named_arg("vesting_cliff");
+    let vesting_duration: u64 = runtime::get_named_arg("vesting_duration");
+    if creator_allocation_bps > 0 {
+        // Capped by the controller, as in the token factory
+        let max_allocation_bps: u64 = if let Key::AddressableEntity(entity_addr) = controller {
+            let controller_contract = AddressableEntityHash::new(entity_addr.value());
+            runtime::call_contract(
+                controller_contract.into(),
+                "max_creator_allocation_bps",
+                runtime_args! {},
+            )
+        } else {
+            DEFAULT_MAX_CREATOR_ALLOCATION_BPS
+        };
+        if creator_allocation_bps > max_allocation_bps {
+            runtime::revert(BondingCurveError::AllocationTooHigh);
+        }
+    }
+    let creator_allocation = (total_supply * U256::from(creator_allocation_bps)) / U256::from(10000u64);
+
+    let curve = CurveType::from_u8(curve_type)
+        .unwrap_or_revert_with(BondingCurveError::InvalidCurveType);
+    let (base_price, max_price) = if curve == CurveType::Piecewise {
+        curves::validate_control_points(&curve_points).unwrap_or_revert();
+        (curve_points[0].1, curve_points[curve_points.len() - 1].1)
+    } else {
+        (base_price, max_price)
+    };
 
     let mut named_keys = NamedKeys::new();
 
@@ -930,17 +2643,72 @@ pub extern "C" fn call() {
     named_keys.insert(TOTAL_SUPPLY.to_string(), storage::new_uref(total_supply).into());
     named_keys.insert(BASE_PRICE.to_string(), storage::new_uref(base_price).into());
     named_keys.insert(MAX_PRICE.to_string(), storage::new_uref(max_price).into());
+    named_keys.insert(CURVE_POINTS.to_string(), storage::new_uref(curve_points).into());
     named_keys.insert(STATUS.to_string(), storage::new_uref(STATUS_ACTIVE).into());
+    named_keys.insert(TOTAL_PURCHASES.to_string(), storage::new_uref(U512::zero()).into());
     named_keys.insert(PROMO_BUDGET.to_string(), storage::new_uref(promo_budget).into());
     named_keys.insert(PROMO_RELEASED.to_string(), storage::new_uref(U512::zero()).into());
     named_keys.insert(
         ACCUMULATED_FEES.to_string(),
         storage::new_uref(U512::zero()).into(),
     );
+    named_keys.insert(BURNED_FEES.to_string(), storage::new_uref(U512::zero()).into());
     named_keys.insert(
         PLATFORM_WALLET.to_string(),
         storage::new_uref(platform_wallet).into(),
     );
+    named_keys.insert(FEE_SCHEDULE.to_string(), storage::new_uref(fee_schedule).into());
+    named_keys.insert(CONTROLLER.to_string(), storage::new_uref(controller).into());
+    named_keys.insert(DEX_FACTORY.to_string(), storage::new_uref(dex_factory).into());
+    named_keys.insert(WCSPR.to_string(), storage::new_uref(wcspr).into());
+    named_keys.insert(QUOTE_TOKEN.to_string(), storage::new_uref(quote_token).into());
+    named_keys.insert(
+        LP_LOCK_DURATION.to_string(),
+        storage::new_uref(lp_lock_duration).into(),
+    );
+    named_keys.insert(
+        GRADUATION_PAIR.to_string(),
+        storage::new_uref(graduation_pair).into(),
+    );
+    named_keys.insert(DEX_PAIR.to_string(), storage::new_uref(None::<Key>).into());
+    named_keys.insert(LP_AMOUNT.to_string(), storage::new_uref(U256::zero()).into());
+    named_keys.insert(LP_UNLOCK_TIME.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(
+        LAUNCH_TIME.to_string(),
+        storage::new_uref(launch_time).into(),
+    );
+    named_keys.insert(SNIPE_WINDOW.to_string(), storage::new_uref(snipe_window).into());
+    named_keys.insert(
+        MAX_WALLET_CSPR.to_string(),
+        storage::new_uref(max_wallet_cspr).into(),
+    );
+    named_keys.insert(
+        MAX_HOLDING_BPS.to_string(),
+        storage::new_uref(max_holding_bps).into(),
+    );
+    named_keys.insert(BUY_COOLDOWN.to_string(), storage::new_uref(buy_cooldown).into());
+    named_keys.insert(MERKLE_ROOT.to_string(), storage::new_uref(merkle_root).into());
+    named_keys.insert(
+        PUBLIC_START_TIME.to_string(),
+        storage::new_uref(public_start_time).into(),
+    );
+    named_keys.insert(PRESALE_CAP.to_string(), storage::new_uref(presale_cap).into());
+    named_keys.insert(
+        CREATOR_ALLOCATION.to_string(),
+        storage::new_uref(creator_allocation).into(),
+    );
+    named_keys.insert(VESTING_CLIFF.to_string(), storage::new_uref(vesting_cliff).into());
+    named_keys.insert(
+        VESTING_DURATION.to_string(),
+        storage::new_uref(vesting_duration).into(),
+    );
+    named_keys.insert(VESTING_START.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(VESTED_CLAIMED.to_string(), storage::new_uref(U256::zero()).into());
+    named_keys.insert(TRADE_COUNT.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(REFUND_WINDOW.to_string(), storage::new_uref(refund_window).into());
+    named_keys.insert(REFUND_END.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(REFUND_POOL.to_string(), storage::new_uref(U512::zero()).into());
+    named_keys.insert(REFUND_CLAIMED.to_string(), storage::new_uref(U512::zero()).into());
     named_keys.insert(LOCKED.to_string(), storage::new_uref(false).into());
     named_keys.insert(INITIALIZED.to_string(), storage::new_uref(false).into());
 
@@ -954,6 +2722,71 @@ pub extern "C" fn call() {
 
     runtime::put_key("ectoplasm_bonding_curve", contract_hash.into());
 
+    // The deployer funds the promo escrow from their main purse
+    let promo_funding = system::create_purse();
+    if !promo_budget.is_zero() {
+        system::transfer_from_purse_to_purse(
+            account::get_main_purse(),
+            promo_funding,
+            promo_budget,
+            None,
+        )
+        .unwrap_or_revert_with(BondingCurveError::InsufficientPayment);
+    }
+
     // Initialize (creates dictionaries)
-    runtime::call_contract::<()>(contract_hash, "init", runtime_args! {});
+    runtime::call_contract::<()>(
+        contract_hash,
+        "init",
+        runtime_args! {
+            "self_key" => Key::Hash(contract_hash.value()),
+            "promo_funding" => promo_funding
+        },
+    );
+
+    // Dev buy in the install deploy itself, subject to the regular buy limits
+    if !initial_buy_cspr.is_zero() {
+        let buy_purse = match quote_token {
+            Some(token) => {
+                // Let the new curve pull the creator's quote tokens; the token
+                // identifies the curve as its spender by its contract hash
+                runtime::call_contract::<()>(
+                    get_contract_hash(token),
+                    "approve",
+                    runtime_args! {
+                        "spender" => Key::Hash(contract_hash.value()),
+                        "amount" => curves::to_u256(initial_buy_cspr).unwrap_or_revert()
+                    },
+                );
+                None
+            }
+            None => {
+                let buy_purse = system::create_purse();
+                system::transfer_from_purse_to_purse(
+                    account::get_main_purse(),
+                    buy_purse,
+                    initial_buy_cspr,
+                    None,
+                )
+                .unwrap_or_revert_with(BondingCurveError::InsufficientPayment);
+                Some(buy_purse)
+            }
+        };
+
+        let tokens_received: U256 = runtime::call_contract(
+            contract_hash,
+            "buy",
+            runtime_args! {
+                "amount" => initial_buy_cspr,
+                "purse" => buy_purse,
+                "min_tokens_out" => U256::zero(),
+                "deadline" => Option::<u64>::None,
+                "merkle_proof" => initial_buy_proof
+            },
+        );
+        runtime::put_key(
+            "ectoplasm_bonding_curve_dev_buy",
+            storage::new_uref(tokens_received).into(),
+        );
+    }
 }
@@ -2,6 +2,7 @@ use casper_contract::contract_api::storage;
 use casper_types::{Key, U256};
 
 use crate::data::{get_dictionary_uref, key_to_str, BALANCES};
+use crate::holders;
 
 /// Read balance from the balances dictionary
 pub fn read_balance(owner: &Key) -> U256 {
@@ -12,9 +13,11 @@ pub fn read_balance(owner: &Key) -> U256 {
         .unwrap_or_default()
 }
 
-/// Write balance to the balances dictionary
+/// Write balance to the balances dictionary, keeping holder stats in sync
 pub fn write_balance(owner: &Key, amount: U256) {
+    let previous = read_balance(owner);
     let dict_uref = get_dictionary_uref(BALANCES);
     let key = key_to_str(owner);
     storage::dictionary_put(dict_uref, &key, amount);
+    holders::record_balance(owner, previous, amount);
 }
@@ -16,6 +16,8 @@ pub const TOTAL_SUPPLY: &str = "total_supply";
 pub const BALANCES: &str = "balances";
 pub const ALLOWANCES: &str = "allowances";
 pub const ADMIN: &str = "admin";
+pub const HOLDER_COUNT: &str = "holder_count";
+pub const TOP_HOLDERS: &str = "top_holders";
 pub const CONTRACT_HASH: &str = "cep18_token_contract";
 
 /// Read a value from a named key
@@ -0,0 +1,44 @@
+use alloc::vec::Vec;
+use casper_types::{Key, U256};
+
+use crate::data::{read_named_key, write_named_key, HOLDER_COUNT, TOP_HOLDERS};
+
+/// Number of accounts kept on the top-holder leaderboard
+pub const MAX_TOP_HOLDERS: usize = 10;
+
+/// Update the holder count and leaderboard after a balance change
+///
+/// The leaderboard only sees accounts whose balance changes, so after a listed
+/// holder's balance drops it may briefly miss a larger unlisted holder until
+/// that holder's balance next changes.
+pub fn record_balance(owner: &Key, previous: U256, current: U256) {
+    if previous.is_zero() != current.is_zero() {
+        let count: u64 = read_named_key(HOLDER_COUNT);
+        let count = if current.is_zero() {
+            count.saturating_sub(1)
+        } else {
+            count + 1
+        };
+        write_named_key(HOLDER_COUNT, count);
+    }
+
+    let mut top: Vec<(Key, U256)> = read_named_key(TOP_HOLDERS);
+    let listed = top.iter().any(|(holder, _)| holder == owner);
+    let qualifies = !current.is_zero()
+        && (top.len() < MAX_TOP_HOLDERS
+            || top.last().map_or(true, |(_, balance)| current > *balance));
+    if !listed && !qualifies {
+        return;
+    }
+
+    top.retain(|(holder, _)| holder != owner);
+    if !current.is_zero() {
+        let position = top
+            .iter()
+            .position(|(_, balance)| current > *balance)
+            .unwrap_or(top.len());
+        top.insert(position, (*owner, current));
+        top.truncate(MAX_TOP_HOLDERS);
+    }
+    write_named_key(TOP_HOLDERS, top);
+}
@@ -6,3 +6,4 @@ pub mod allowances;
 pub mod balances;
 pub mod data;
 pub mod error;
+pub mod holders;
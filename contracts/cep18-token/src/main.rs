@@ -7,8 +7,9 @@ mod data;
 mod balances;
 mod allowances;
 mod error;
+mod holders;
 
-use alloc::{string::{String, ToString}, vec, vec::Vec};
+use alloc::{boxed::Box, string::{String, ToString}, vec, vec::Vec};
 use casper_contract::{
     contract_api::{runtime, storage},
     unwrap_or_revert::UnwrapOrRevert,
@@ -19,10 +20,11 @@ use casper_types::{
     contracts::NamedKeys,
     CLType, CLValue, EntryPointAccess, EntryPointPayment, EntryPointType, Key, Parameter, RuntimeArgs, U256,
 };
+use ectoplasm_common::caller_key;
 
 use data::{
     ALLOWANCES, BALANCES, DECIMALS, NAME, SYMBOL, TOTAL_SUPPLY,
-    ADMIN, CONTRACT_HASH,
+    ADMIN, CONTRACT_HASH, HOLDER_COUNT, TOP_HOLDERS,
 };
 use error::Cep18Error;
 
@@ -59,6 +61,28 @@ pub extern "C" fn balance_of() {
     runtime::ret(CLValue::from_t(balance).unwrap_or_revert());
 }
 
+/// Holder count plus the top holders as (holder, balance, share of supply in bps)
+#[no_mangle]
+pub extern "C" fn get_holder_stats() {
+    let count: u64 = data::read_named_key(HOLDER_COUNT);
+    let top: Vec<(Key, U256)> = data::read_named_key(TOP_HOLDERS);
+    let total_supply: U256 = data::read_named_key(TOTAL_SUPPLY);
+
+    let holders: Vec<(Key, U256, u64)> = top
+        .into_iter()
+        .map(|(holder, balance)| {
+            let share_bps = if total_supply.is_zero() {
+                0
+            } else {
+                ((balance * U256::from(10000u64)) / total_supply).as_u64()
+            };
+            (holder, balance, share_bps)
+        })
+        .collect();
+
+    runtime::ret(CLValue::from_t((count, holders)).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn allowance() {
     let owner: Key = runtime::get_named_arg("owner");
@@ -72,8 +96,7 @@ pub extern "C" fn transfer() {
     let recipient: Key = runtime::get_named_arg("recipient");
     let amount: U256 = runtime::get_named_arg("amount");
 
-    let caller = runtime::get_caller();
-    let sender = Key::Account(caller);
+    let sender = caller_key();
 
     transfer_internal(&sender, &recipient, amount);
 }
@@ -84,8 +107,7 @@ pub extern "C" fn transfer_from() {
     let recipient: Key = runtime::get_named_arg("recipient");
     let amount: U256 = runtime::get_named_arg("amount");
 
-    let caller = runtime::get_caller();
-    let spender = Key::Account(caller);
+    let spender = caller_key();
 
     // Check allowance
     let current_allowance = allowances::read_allowance(&owner, &spender);
@@ -105,8 +127,7 @@ pub extern "C" fn approve() {
     let spender: Key = runtime::get_named_arg("spender");
     let amount: U256 = runtime::get_named_arg("amount");
 
-    let caller = runtime::get_caller();
-    let owner = Key::Account(caller);
+    let owner = caller_key();
 
     allowances::write_allowance(&owner, &spender, amount);
 }
@@ -222,6 +243,22 @@ fn get_entry_points() -> EntryPoints {
         EntryPointPayment::Caller,
     ));
 
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_holder_stats",
+        vec![],
+        CLType::Tuple2([
+            Box::new(CLType::U64),
+            Box::new(CLType::List(Box::new(CLType::Tuple3([
+                Box::new(CLType::Key),
+                Box::new(CLType::U256),
+                Box::new(CLType::U64),
+            ])))),
+        ]),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
     entry_points.add_entry_point(EntryPoint::new(
         "allowance",
         vec![
@@ -328,6 +365,15 @@ pub extern "C" fn call() {
     let admin_uref = storage::new_uref(admin);
     named_keys.insert(ADMIN.to_string(), admin_uref.into());
 
+    // Holder stats start with the deployer when there is an initial supply
+    let (holder_count, top_holders): (u64, Vec<(Key, U256)>) = if initial_supply > U256::zero() {
+        (1, vec![(admin, initial_supply)])
+    } else {
+        (0, Vec::new())
+    };
+    named_keys.insert(HOLDER_COUNT.to_string(), storage::new_uref(holder_count).into());
+    named_keys.insert(TOP_HOLDERS.to_string(), storage::new_uref(top_holders).into());
+
     // Create dictionaries for balances and allowances
     let balances_dict = storage::new_dictionary(BALANCES).unwrap_or_revert();
     named_keys.insert(BALANCES.to_string(), balances_dict.into());
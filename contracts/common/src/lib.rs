@@ -0,0 +1,40 @@
+#![no_std]
+
+//! Helpers shared by the Ectoplasm contracts
+
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    account::AccountHash, bytesrepr::FromBytes, contracts::ContractHash, system::CallerInfo,
+    ApiError, CLTyped, EntityAddr, Key,
+};
+
+// Field indices of `CallerInfo`
+const CALLER_ACCOUNT: u8 = 0;
+const CALLER_ENTITY: u8 = 3;
+const CALLER_CONTRACT: u8 = 4;
+
+/// Key of the immediate caller.
+///
+/// `runtime::get_caller` always names the account that signed the deploy,
+/// even several contract calls deep, so a token or pair keyed on it debits
+/// that account instead of the contract actually calling. This returns the
+/// account for direct calls and `Key::Hash(<contract hash>)` for a calling
+/// contract, which lets routers, pairs, curves and settlement contracts hold,
+/// send and spend balances of their own.
+pub fn caller_key() -> Key {
+    let caller = runtime::get_immediate_caller().unwrap_or_revert();
+    if let Some(account_hash) = caller_field::<AccountHash>(&caller, CALLER_ACCOUNT) {
+        return Key::Account(account_hash);
+    }
+    if let Some(contract_hash) = caller_field::<ContractHash>(&caller, CALLER_CONTRACT) {
+        return Key::Hash(contract_hash.value());
+    }
+    if let Some(entity_addr) = caller_field::<EntityAddr>(&caller, CALLER_ENTITY) {
+        return Key::Hash(entity_addr.value());
+    }
+    runtime::revert(ApiError::InvalidCallerInfoRequest)
+}
+
+fn caller_field<T: CLTyped + FromBytes>(caller: &CallerInfo, index: u8) -> Option<T> {
+    caller.get_field_by_index(index)?.to_t::<Option<T>>().ok().flatten()
+}
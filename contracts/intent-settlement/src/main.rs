@@ -0,0 +1,383 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+mod route;
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use casper_contract::{
+    contract_api::{cryptography, runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    account::AccountHash,
+    addressable_entity::{EntityEntryPoint as EntryPoint, EntryPoints},
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    contracts::{ContractHash, NamedKeys},
+    runtime_args, CLType, CLTyped, CLValue, EntryPointAccess, EntryPointPayment,
+    EntryPointType, Key, Parameter, PublicKey, Signature, URef, U256,
+};
+
+use route::SwapIntent;
+
+// Storage keys
+const ROUTER: &str = "router";
+const SETTLEMENT: &str = "settlement";
+const NONCES: &str = "nonces";
+
+// Domain tag prepended to every signed intent
+const INTENT_DOMAIN: &str = "ectoplasm-swap-intent-v1";
+
+// Error codes
+const ERROR_ALREADY_INITIALIZED: u16 = 1;
+const ERROR_FAILED_TO_CREATE_DICTIONARY: u16 = 2;
+const ERROR_INVALID_SIGNATURE: u16 = 3;
+const ERROR_INVALID_NONCE: u16 = 4;
+const ERROR_EXPIRED: u16 = 5;
+const ERROR_INVALID_PATH: u16 = 6;
+const ERROR_INSUFFICIENT_OUTPUT_AMOUNT: u16 = 7;
+const ERROR_INVALID_CONTRACT: u16 = 8;
+
+// ============ Helper Functions ============
+
+fn read_from_uref<T: CLTyped + FromBytes>(name: &str) -> T {
+    let key = runtime::get_key(name).unwrap_or_revert();
+    let uref = key.into_uref().unwrap_or_revert();
+    storage::read(uref).unwrap_or_revert().unwrap_or_revert()
+}
+
+fn get_dictionary_uref(name: &str) -> URef {
+    runtime::get_key(name)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+fn key_to_str(key: &Key) -> String {
+    match key {
+        Key::Account(account_hash) => hex_encode(account_hash.as_bytes()),
+        Key::Hash(hash) => hex_encode(hash),
+        _ => hex_encode(&key.to_bytes().unwrap_or_revert()),
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    let mut result = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        result.push(hex_char(byte >> 4));
+        result.push(hex_char(byte & 0x0f));
+    }
+    result
+}
+
+fn hex_char(nibble: u8) -> char {
+    match nibble {
+        0..=9 => (b'0' + nibble) as char,
+        10..=15 => (b'a' + nibble - 10) as char,
+        _ => '0',
+    }
+}
+
+fn get_contract_hash(key: Key) -> ContractHash {
+    match key {
+        Key::Hash(hash) => ContractHash::new(hash),
+        _ => runtime::revert(casper_types::ApiError::User(ERROR_INVALID_CONTRACT)),
+    }
+}
+
+fn get_current_time() -> u64 {
+    runtime::get_blocktime().into()
+}
+
+fn read_nonce(owner: &Key) -> u64 {
+    let dict_uref = get_dictionary_uref(NONCES);
+    storage::dictionary_get(dict_uref, &key_to_str(owner))
+        .unwrap_or_default()
+        .unwrap_or_default()
+}
+
+fn write_nonce(owner: &Key, nonce: u64) {
+    let dict_uref = get_dictionary_uref(NONCES);
+    storage::dictionary_put(dict_uref, &key_to_str(owner), nonce);
+}
+
+// ============ Intents ============
+
+impl SwapIntent {
+    fn from_args(owner: Key) -> Self {
+        SwapIntent {
+            owner,
+            path: runtime::get_named_arg("path"),
+            amount_in: runtime::get_named_arg("amount_in"),
+            min_out: runtime::get_named_arg("min_out"),
+            deadline: runtime::get_named_arg("deadline"),
+            nonce: runtime::get_named_arg("nonce"),
+            relayer_fee: runtime::get_named_arg("relayer_fee"),
+        }
+    }
+
+    /// blake2b digest the owner signs. Binds the intent to this settlement
+    /// contract so a signature cannot be replayed against another deployment.
+    fn digest(&self) -> [u8; 32] {
+        let settlement: Key = read_from_uref(SETTLEMENT);
+        let mut message = INTENT_DOMAIN.as_bytes().to_vec();
+        let payload = (
+            settlement,
+            self.owner,
+            self.path.clone(),
+            self.amount_in,
+            self.min_out,
+            (self.deadline, self.nonce, self.relayer_fee),
+        );
+        message.extend(payload.to_bytes().unwrap_or_revert());
+        runtime::blake2b(message)
+    }
+}
+
+fn owner_from_public_key(public_key: &PublicKey) -> Key {
+    Key::Account(AccountHash::from(public_key))
+}
+
+fn verify_intent_signature(intent: &SwapIntent, public_key: &PublicKey, signature: &Bytes) {
+    let (signature, remainder) = Signature::from_bytes(signature.as_slice())
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_INVALID_SIGNATURE));
+    if !remainder.is_empty() {
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_SIGNATURE));
+    }
+
+    cryptography::verify_signature(intent.digest(), &signature, public_key)
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_INVALID_SIGNATURE));
+}
+
+// ============ External Contract Calls ============
+
+/// Tokens and the router reached through contract calls, made as this contract
+struct ContractDex {
+    settlement: Key,
+    router: Key,
+}
+
+impl route::Dex for ContractDex {
+    fn settlement(&self) -> Key {
+        self.settlement
+    }
+
+    fn router(&self) -> Key {
+        self.router
+    }
+
+    fn transfer(&mut self, token: Key, recipient: Key, amount: U256) {
+        runtime::call_contract::<()>(
+            get_contract_hash(token),
+            "transfer",
+            runtime_args! {
+                "recipient" => recipient,
+                "amount" => amount
+            },
+        );
+    }
+
+    fn transfer_from(&mut self, token: Key, owner: Key, recipient: Key, amount: U256) {
+        runtime::call_contract::<()>(
+            get_contract_hash(token),
+            "transfer_from",
+            runtime_args! {
+                "owner" => owner,
+                "recipient" => recipient,
+                "amount" => amount
+            },
+        );
+    }
+
+    fn approve(&mut self, token: Key, spender: Key, amount: U256) {
+        runtime::call_contract::<()>(
+            get_contract_hash(token),
+            "approve",
+            runtime_args! {
+                "spender" => spender,
+                "amount" => amount
+            },
+        );
+    }
+
+    fn swap_exact_tokens_for_tokens(
+        &mut self,
+        amount_in: U256,
+        amount_out_min: U256,
+        path: &[Key],
+        to: Key,
+        deadline: u64,
+    ) -> Vec<U256> {
+        runtime::call_contract(
+            get_contract_hash(self.router),
+            "swap_exact_tokens_for_tokens",
+            runtime_args! {
+                "amount_in" => amount_in,
+                "amount_out_min" => amount_out_min,
+                "path" => path.to_vec(),
+                "to" => to,
+                "deadline" => deadline
+            },
+        )
+    }
+}
+
+// ============ Entry Points ============
+
+/// Initialize the nonce dictionary and record the contract's own key.
+/// Called after contract creation.
+#[no_mangle]
+pub extern "C" fn init() {
+    if runtime::get_key(NONCES).is_some() {
+        runtime::revert(casper_types::ApiError::User(ERROR_ALREADY_INITIALIZED));
+    }
+
+    storage::new_dictionary(NONCES)
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_FAILED_TO_CREATE_DICTIONARY));
+
+    let settlement: Key = runtime::get_named_arg("settlement");
+    runtime::put_key(SETTLEMENT, storage::new_uref(settlement).into());
+}
+
+#[no_mangle]
+pub extern "C" fn router() {
+    let router: Key = read_from_uref(ROUTER);
+    runtime::ret(CLValue::from_t(router).unwrap_or_revert());
+}
+
+/// Get the next nonce an owner's intent must use
+#[no_mangle]
+pub extern "C" fn nonce() {
+    let owner: Key = runtime::get_named_arg("owner");
+    runtime::ret(CLValue::from_t(read_nonce(&owner)).unwrap_or_revert());
+}
+
+/// Get the digest an owner must sign for the given intent
+#[no_mangle]
+pub extern "C" fn intent_digest() {
+    let public_key: PublicKey = runtime::get_named_arg("public_key");
+    let intent = SwapIntent::from_args(owner_from_public_key(&public_key));
+    runtime::ret(CLValue::from_t(intent.digest()).unwrap_or_revert());
+}
+
+/// Settle a signed swap intent on behalf of its owner.
+/// The owner approves this contract for `amount_in` of the input token; the
+/// swap runs through the router's `swap_exact_tokens_for_tokens` without a
+/// referral, so the router's price impact limit and deadline apply and the
+/// relayer never handles the owner's tokens. The caller acts as relayer and receives
+/// `relayer_fee` in the output token.
+/// Returns the amount of output token delivered to the owner.
+#[no_mangle]
+pub extern "C" fn settle_intent() {
+    let public_key: PublicKey = runtime::get_named_arg("public_key");
+    let signature: Bytes = runtime::get_named_arg("signature");
+
+    let owner = owner_from_public_key(&public_key);
+    let intent = SwapIntent::from_args(owner);
+
+    if get_current_time() > intent.deadline {
+        runtime::revert(casper_types::ApiError::User(ERROR_EXPIRED));
+    }
+
+    verify_intent_signature(&intent, &public_key, &signature);
+
+    // Consume the nonce before any external call
+    let expected_nonce = read_nonce(&owner);
+    if intent.nonce != expected_nonce {
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_NONCE));
+    }
+    write_nonce(&owner, expected_nonce + 1);
+
+    let mut dex = ContractDex {
+        settlement: read_from_uref(SETTLEMENT),
+        router: read_from_uref(ROUTER),
+    };
+    let relayer = Key::Account(runtime::get_caller());
+    let amount_to_owner = route::settle(&mut dex, &intent, relayer)
+        .unwrap_or_else(|error| runtime::revert(casper_types::ApiError::User(error)));
+
+    runtime::ret(CLValue::from_t(amount_to_owner).unwrap_or_revert());
+}
+
+// ============ Contract Installation ============
+
+fn intent_parameters() -> Vec<Parameter> {
+    vec![
+        Parameter::new("public_key", CLType::PublicKey),
+        Parameter::new("path", CLType::List(Box::new(CLType::Key))),
+        Parameter::new("amount_in", CLType::U256),
+        Parameter::new("min_out", CLType::U256),
+        Parameter::new("deadline", CLType::U64),
+        Parameter::new("nonce", CLType::U64),
+        Parameter::new("relayer_fee", CLType::U256),
+    ]
+}
+
+fn get_entry_points() -> EntryPoints {
+    let mut ep = EntryPoints::new();
+
+    ep.add_entry_point(EntryPoint::new(
+        "init",
+        vec![Parameter::new("settlement", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
+    ));
+    ep.add_entry_point(EntryPoint::new("router", vec![], CLType::Key, EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller));
+
+    ep.add_entry_point(EntryPoint::new(
+        "nonce",
+        vec![Parameter::new("owner", CLType::Key)],
+        CLType::U64,
+        EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
+    ));
+
+    ep.add_entry_point(EntryPoint::new(
+        "intent_digest",
+        intent_parameters(),
+        CLType::ByteArray(32),
+        EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
+    ));
+
+    let mut settle_parameters = intent_parameters();
+    settle_parameters.push(Parameter::new("signature", Bytes::cl_type()));
+    ep.add_entry_point(EntryPoint::new(
+        "settle_intent",
+        settle_parameters,
+        CLType::U256,
+        EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
+    ));
+
+    ep
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let router: Key = runtime::get_named_arg("router");
+
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(ROUTER.to_string(), storage::new_uref(router).into());
+
+    let (contract_hash, _) = storage::new_contract(
+        get_entry_points(),
+        Some(named_keys),
+        Some("ectoplasm_intent_settlement_package".to_string()),
+        Some("ectoplasm_intent_settlement_access".to_string()),
+        None,
+    );
+
+    runtime::put_key("ectoplasm_intent_settlement_contract", contract_hash.into());
+
+    // Call init to create dictionaries in contract context. The contract's own
+    // key is used as token holder and as the signing domain for intents.
+    runtime::call_contract::<()>(
+        contract_hash,
+        "init",
+        runtime_args! {
+            "settlement" => Key::Hash(contract_hash.value())
+        },
+    );
+}
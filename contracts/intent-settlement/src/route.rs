@@ -0,0 +1,273 @@
+use alloc::vec::Vec;
+use casper_types::{Key, U256};
+
+use crate::{ERROR_INSUFFICIENT_OUTPUT_AMOUNT, ERROR_INVALID_PATH};
+
+/// Token and router operations a settlement performs. Every call is made by
+/// the settlement contract itself, so `transfer` and `approve` act on its own
+/// balance and `transfer_from` spends the allowance an owner granted to it.
+pub trait Dex {
+    /// Key the settlement contract holds tokens under
+    fn settlement(&self) -> Key;
+    /// Key the router pulls input tokens as
+    fn router(&self) -> Key;
+    fn transfer(&mut self, token: Key, recipient: Key, amount: U256);
+    fn transfer_from(&mut self, token: Key, owner: Key, recipient: Key, amount: U256);
+    fn approve(&mut self, token: Key, spender: Key, amount: U256);
+    /// The router's `swap_exact_tokens_for_tokens`; returns the amount at every hop
+    fn swap_exact_tokens_for_tokens(
+        &mut self,
+        amount_in: U256,
+        amount_out_min: U256,
+        path: &[Key],
+        to: Key,
+        deadline: u64,
+    ) -> Vec<U256>;
+}
+
+/// A swap authorised off-chain by `owner` and submitted by a relayer
+pub struct SwapIntent {
+    pub owner: Key,
+    pub path: Vec<Key>,
+    pub amount_in: U256,
+    pub min_out: U256,
+    pub deadline: u64,
+    pub nonce: u64,
+    pub relayer_fee: U256,
+}
+
+/// Pull `amount_in` of the owner's `path[0]` into the settlement contract and
+/// swap it through the router, which applies its quoting, price impact limit
+/// and deadline. The output is collected by the settlement contract, which
+/// then pays the relayer its fee and the owner the rest.
+/// Returns the amount of output token delivered to the owner.
+pub fn settle<D: Dex>(dex: &mut D, intent: &SwapIntent, relayer: Key) -> Result<U256, u16> {
+    let SwapIntent { owner, ref path, amount_in, min_out, deadline, relayer_fee, .. } = *intent;
+    if path.len() < 2 {
+        return Err(ERROR_INVALID_PATH);
+    }
+
+    // Owner's minimum is net of the relayer fee
+    let amount_out_min = min_out
+        .checked_add(relayer_fee)
+        .ok_or(ERROR_INSUFFICIENT_OUTPUT_AMOUNT)?;
+
+    let settlement = dex.settlement();
+    let router = dex.router();
+    dex.transfer_from(path[0], owner, settlement, amount_in);
+    dex.approve(path[0], router, amount_in);
+    let amounts = dex.swap_exact_tokens_for_tokens(amount_in, amount_out_min, path, settlement, deadline);
+
+    let amount_out = amounts.last().copied().unwrap_or_default();
+    if amount_out < amount_out_min {
+        return Err(ERROR_INSUFFICIENT_OUTPUT_AMOUNT);
+    }
+
+    // Pay the relayer and deliver the rest to the owner
+    let token_out = path[path.len() - 1];
+    let amount_to_owner = amount_out - relayer_fee;
+    if !relayer_fee.is_zero() {
+        dex.transfer(token_out, relayer, relayer_fee);
+    }
+    dex.transfer(token_out, owner, amount_to_owner);
+
+    Ok(amount_to_owner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::BTreeMap;
+    use alloc::vec;
+    use casper_types::account::AccountHash;
+
+    /// In-memory CEP-18 tokens and a router stub that pays out a fixed rate per
+    /// hop. Token calls are made by the settlement contract; the router pulls
+    /// its input with the settlement's allowance.
+    struct MockDex {
+        settlement: Key,
+        router: Key,
+        balances: BTreeMap<(Key, Key), U256>,
+        allowances: BTreeMap<(Key, Key, Key), U256>,
+        // Minimum output the last router swap was asked for
+        last_amount_out_min: Option<U256>,
+    }
+
+    impl MockDex {
+        fn new(settlement: Key, router: Key) -> Self {
+            MockDex {
+                settlement,
+                router,
+                balances: BTreeMap::new(),
+                allowances: BTreeMap::new(),
+                last_amount_out_min: None,
+            }
+        }
+
+        fn balance(&self, token: Key, owner: Key) -> U256 {
+            self.balances.get(&(token, owner)).copied().unwrap_or_default()
+        }
+
+        fn allowance(&self, token: Key, owner: Key, spender: Key) -> U256 {
+            self.allowances.get(&(token, owner, spender)).copied().unwrap_or_default()
+        }
+
+        fn mint(&mut self, token: Key, owner: Key, amount: U256) {
+            let balance = self.balance(token, owner);
+            self.balances.insert((token, owner), balance + amount);
+        }
+
+        fn move_tokens(&mut self, token: Key, from: Key, to: Key, amount: U256) {
+            let from_balance = self.balance(token, from);
+            assert!(from_balance >= amount, "insufficient balance");
+            self.balances.insert((token, from), from_balance - amount);
+            self.mint(token, to, amount);
+        }
+
+        fn spend_allowance(&mut self, token: Key, owner: Key, spender: Key, amount: U256) {
+            let allowance = self.allowance(token, owner, spender);
+            assert!(allowance >= amount, "insufficient allowance");
+            self.allowances.insert((token, owner, spender), allowance - amount);
+        }
+    }
+
+    impl Dex for MockDex {
+        fn settlement(&self) -> Key {
+            self.settlement
+        }
+
+        fn router(&self) -> Key {
+            self.router
+        }
+
+        fn transfer(&mut self, token: Key, recipient: Key, amount: U256) {
+            let sender = self.settlement;
+            self.move_tokens(token, sender, recipient, amount);
+        }
+
+        fn transfer_from(&mut self, token: Key, owner: Key, recipient: Key, amount: U256) {
+            let spender = self.settlement;
+            self.spend_allowance(token, owner, spender, amount);
+            self.move_tokens(token, owner, recipient, amount);
+        }
+
+        fn approve(&mut self, token: Key, spender: Key, amount: U256) {
+            let owner = self.settlement;
+            self.allowances.insert((token, owner, spender), amount);
+        }
+
+        fn swap_exact_tokens_for_tokens(
+            &mut self,
+            amount_in: U256,
+            amount_out_min: U256,
+            path: &[Key],
+            to: Key,
+            _deadline: u64,
+        ) -> Vec<U256> {
+            let (settlement, router) = (self.settlement, self.router);
+            self.last_amount_out_min = Some(amount_out_min);
+            self.spend_allowance(path[0], settlement, router, amount_in);
+            self.move_tokens(path[0], settlement, router, amount_in);
+
+            let mut amounts = vec![amount_in];
+            for _ in 1..path.len() {
+                amounts.push(amounts[amounts.len() - 1] * RATE);
+            }
+            let amount_out = amounts[amounts.len() - 1];
+            assert!(amount_out >= amount_out_min, "insufficient output");
+            self.mint(path[path.len() - 1], to, amount_out);
+            amounts
+        }
+    }
+
+    fn hash(byte: u8) -> Key {
+        Key::Hash([byte; 32])
+    }
+
+    fn account(byte: u8) -> Key {
+        Key::Account(AccountHash::new([byte; 32]))
+    }
+
+    const RATE: u64 = 2;
+    const TOKEN_A: u8 = 1;
+    const TOKEN_B: u8 = 2;
+    const TOKEN_C: u8 = 3;
+    const ROUTER: u8 = 10;
+    const SETTLEMENT: u8 = 20;
+    const OWNER: u8 = 30;
+    const RELAYER: u8 = 31;
+
+    fn intent(path: &[Key], amount_in: U256, min_out: U256, relayer_fee: U256) -> SwapIntent {
+        SwapIntent {
+            owner: account(OWNER),
+            path: path.to_vec(),
+            amount_in,
+            min_out,
+            deadline: 0,
+            nonce: 0,
+            relayer_fee,
+        }
+    }
+
+    fn setup() -> MockDex {
+        let mut dex = MockDex::new(hash(SETTLEMENT), hash(ROUTER));
+        dex.mint(hash(TOKEN_A), account(OWNER), U256::from(10_000u64));
+        dex.allowances.insert(
+            (hash(TOKEN_A), account(OWNER), hash(SETTLEMENT)),
+            U256::from(10_000u64),
+        );
+        dex
+    }
+
+    #[test]
+    fn test_settle_multi_hop_intent_through_router() {
+        let mut dex = setup();
+        let path = [hash(TOKEN_A), hash(TOKEN_B), hash(TOKEN_C)];
+        let amount_in = U256::from(10_000u64);
+        let min_out = U256::from(39_000u64);
+        let fee = U256::from(50u64);
+
+        let delivered = settle(&mut dex, &intent(&path, amount_in, min_out, fee), account(RELAYER)).unwrap();
+
+        // The router is asked for the owner's minimum plus the relayer fee
+        assert_eq!(dex.last_amount_out_min, Some(min_out + fee));
+        assert_eq!(delivered, U256::from(40_000u64) - fee);
+        assert_eq!(dex.balance(hash(TOKEN_A), account(OWNER)), U256::zero());
+        assert_eq!(dex.balance(hash(TOKEN_A), hash(ROUTER)), amount_in);
+        assert_eq!(dex.balance(hash(TOKEN_C), account(OWNER)), delivered);
+        assert_eq!(dex.balance(hash(TOKEN_C), account(RELAYER)), fee);
+
+        // Nothing is left behind in the settlement contract, and the router
+        // holds no leftover allowance over it
+        for token in [TOKEN_A, TOKEN_B, TOKEN_C] {
+            assert_eq!(dex.balance(hash(token), hash(SETTLEMENT)), U256::zero());
+        }
+        assert_eq!(dex.allowance(hash(TOKEN_A), hash(SETTLEMENT), hash(ROUTER)), U256::zero());
+    }
+
+    #[test]
+    fn test_settle_rejects_minimum_plus_fee_overflow() {
+        let mut dex = setup();
+        let path = [hash(TOKEN_A), hash(TOKEN_B)];
+        let result = settle(
+            &mut dex,
+            &intent(&path, U256::from(1_000u64), U256::MAX, U256::one()),
+            account(RELAYER),
+        );
+
+        assert_eq!(result, Err(ERROR_INSUFFICIENT_OUTPUT_AMOUNT));
+        assert_eq!(dex.balance(hash(TOKEN_A), account(OWNER)), U256::from(10_000u64));
+    }
+
+    #[test]
+    fn test_settle_rejects_short_path() {
+        let mut dex = setup();
+        let short = [hash(TOKEN_A)];
+        let result = settle(
+            &mut dex,
+            &intent(&short, U256::from(1_000u64), U256::zero(), U256::zero()),
+            account(RELAYER),
+        );
+        assert_eq!(result, Err(ERROR_INVALID_PATH));
+    }
+}
@@ -24,6 +24,9 @@ const DEFAULT_GRADUATION_THRESHOLD: &str = "default_graduation_threshold";
 const DEFAULT_PLATFORM_FEE_BPS: &str = "default_platform_fee_bps";
 const DEFAULT_DEADLINE_DAYS: &str = "default_deadline_days";
 const TOKEN_FACTORY: &str = "token_factory";
+const MAX_CREATOR_ALLOCATION_BPS: &str = "max_creator_allocation_bps";
+const KEEPER_BOUNTY: &str = "keeper_bounty";
+const KEEPER_BOUNTY_CAP_BPS: &str = "keeper_bounty_cap_bps";
 const INITIALIZED: &str = "initialized";
 
 // ============ Error Codes ============
@@ -33,10 +36,15 @@ const ERROR_ALREADY_INITIALIZED: u16 = 2;
 const ERROR_INVALID_FEE: u16 = 3;
 const ERROR_INVALID_THRESHOLD: u16 = 4;
 const ERROR_INVALID_DEADLINE: u16 = 5;
+const ERROR_INVALID_ALLOCATION: u16 = 6;
+const ERROR_INVALID_BOUNTY: u16 = 7;
 
 // Maximum platform fee: 10% (1000 basis points)
 const MAX_PLATFORM_FEE_BPS: u64 = 1000;
 
+// Hard ceiling for the creator allocation cap: 20% (2000 basis points)
+const CREATOR_ALLOCATION_LIMIT_BPS: u64 = 2000;
+
 // ============ Helper Functions ============
 
 fn read_from_uref<T: CLTyped + FromBytes>(name: &str) -> T {
@@ -99,6 +107,23 @@ pub extern "C" fn default_deadline_days() {
     runtime::ret(CLValue::from_t(days).unwrap_or_revert());
 }
 
+/// Get the maximum creator allocation in basis points of supply
+#[no_mangle]
+pub extern "C" fn max_creator_allocation_bps() {
+    let max_bps: u64 = read_from_uref(MAX_CREATOR_ALLOCATION_BPS);
+    runtime::ret(CLValue::from_t(max_bps).unwrap_or_revert());
+}
+
+/// Get the keeper bounty as (amount in motes, cap_bps)
+/// The bounty is paid out of a launch's platform fees and never exceeds
+/// `cap_bps` of them
+#[no_mangle]
+pub extern "C" fn get_keeper_bounty() {
+    let bounty: U512 = read_from_uref(KEEPER_BOUNTY);
+    let cap_bps: u64 = read_from_uref(KEEPER_BOUNTY_CAP_BPS);
+    runtime::ret(CLValue::from_t((bounty, cap_bps)).unwrap_or_revert());
+}
+
 /// Get the token factory address
 #[no_mangle]
 pub extern "C" fn token_factory() {
@@ -164,6 +189,34 @@ pub extern "C" fn set_default_deadline() {
     write_to_uref(DEFAULT_DEADLINE_DAYS, days);
 }
 
+/// Set the maximum creator allocation in basis points (superadmin only)
+#[no_mangle]
+pub extern "C" fn set_max_creator_allocation() {
+    require_superadmin();
+
+    let max_bps: u64 = runtime::get_named_arg("max_bps");
+    if max_bps > CREATOR_ALLOCATION_LIMIT_BPS {
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_ALLOCATION));
+    }
+
+    write_to_uref(MAX_CREATOR_ALLOCATION_BPS, max_bps);
+}
+
+/// Set the keeper bounty and its cap (superadmin only)
+#[no_mangle]
+pub extern "C" fn set_keeper_bounty() {
+    require_superadmin();
+
+    let bounty: U512 = runtime::get_named_arg("bounty");
+    let cap_bps: u64 = runtime::get_named_arg("cap_bps");
+    if cap_bps > 10000 {
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_BOUNTY));
+    }
+
+    write_to_uref(KEEPER_BOUNTY, bounty);
+    write_to_uref(KEEPER_BOUNTY_CAP_BPS, cap_bps);
+}
+
 /// Set the token factory address (superadmin only, one-time)
 #[no_mangle]
 pub extern "C" fn set_token_factory() {
@@ -234,6 +287,27 @@ fn get_entry_points() -> EntryPoints {
         EntryPointPayment::Caller,
     ));
 
+    entry_points.add_entry_point(EntryPoint::new(
+        "max_creator_allocation_bps",
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_keeper_bounty",
+        vec![],
+        CLType::Tuple2([
+            alloc::boxed::Box::new(CLType::U512),
+            alloc::boxed::Box::new(CLType::U64),
+        ]),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
     entry_points.add_entry_point(EntryPoint::new(
         "token_factory",
         vec![],
@@ -284,6 +358,27 @@ fn get_entry_points() -> EntryPoints {
         EntryPointPayment::Caller,
     ));
 
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_max_creator_allocation",
+        vec![Parameter::new("max_bps", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "set_keeper_bounty",
+        vec![
+            Parameter::new("bounty", CLType::U512),
+            Parameter::new("cap_bps", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
     entry_points.add_entry_point(EntryPoint::new(
         "set_token_factory",
         vec![Parameter::new("factory", CLType::Key)],
@@ -319,6 +414,21 @@ pub extern "C" fn call() {
     let initial_deadline_days: u64 =
         runtime::get_named_arg::<Option<u64>>("initial_deadline_days").unwrap_or(30); // 30 days default
 
+    let initial_max_creator_allocation_bps: u64 =
+        runtime::get_named_arg::<Option<u64>>("initial_max_creator_allocation_bps").unwrap_or(1000); // 10% default
+    if initial_max_creator_allocation_bps > CREATOR_ALLOCATION_LIMIT_BPS {
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_ALLOCATION));
+    }
+
+    let initial_keeper_bounty: U512 = runtime::get_named_arg::<Option<U512>>("initial_keeper_bounty")
+        .unwrap_or_else(|| U512::from(10_000_000_000u64)); // 10 CSPR default
+
+    let initial_keeper_bounty_cap_bps: u64 =
+        runtime::get_named_arg::<Option<u64>>("initial_keeper_bounty_cap_bps").unwrap_or(5000); // 50% of platform fees default
+    if initial_keeper_bounty_cap_bps > 10000 {
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_BOUNTY));
+    }
+
     let mut named_keys = NamedKeys::new();
     let deployer = Key::Account(runtime::get_caller());
 
@@ -339,6 +449,18 @@ pub extern "C" fn call() {
         DEFAULT_DEADLINE_DAYS.to_string(),
         storage::new_uref(initial_deadline_days).into(),
     );
+    named_keys.insert(
+        MAX_CREATOR_ALLOCATION_BPS.to_string(),
+        storage::new_uref(initial_max_creator_allocation_bps).into(),
+    );
+    named_keys.insert(
+        KEEPER_BOUNTY.to_string(),
+        storage::new_uref(initial_keeper_bounty).into(),
+    );
+    named_keys.insert(
+        KEEPER_BOUNTY_CAP_BPS.to_string(),
+        storage::new_uref(initial_keeper_bounty_cap_bps).into(),
+    );
     named_keys.insert(
         TOKEN_FACTORY.to_string(),
         storage::new_uref(Option::<Key>::None).into(),
@@ -17,6 +17,7 @@ use casper_types::{
     runtime_args, CLType, CLTyped, CLValue, EntryPointAccess, EntryPointPayment,
     EntryPointType, Key, Parameter, URef, U256,
 };
+use ectoplasm_common::caller_key;
 
 // Storage keys
 const TOKEN0: &str = "token0";
@@ -307,7 +308,7 @@ pub extern "C" fn allowance() {
 pub extern "C" fn transfer() {
     let recipient: Key = runtime::get_named_arg("recipient");
     let amount: U256 = runtime::get_named_arg("amount");
-    let sender = Key::Account(runtime::get_caller());
+    let sender = caller_key();
     transfer_lp_internal(&sender, &recipient, amount);
 }
 
@@ -316,7 +317,7 @@ pub extern "C" fn transfer_from() {
     let owner: Key = runtime::get_named_arg("owner");
     let recipient: Key = runtime::get_named_arg("recipient");
     let amount: U256 = runtime::get_named_arg("amount");
-    let spender = Key::Account(runtime::get_caller());
+    let spender = caller_key();
 
     let current_allowance = read_lp_allowance(&owner, &spender);
     if current_allowance < amount {
@@ -331,7 +332,7 @@ pub extern "C" fn transfer_from() {
 pub extern "C" fn approve() {
     let spender: Key = runtime::get_named_arg("spender");
     let amount: U256 = runtime::get_named_arg("amount");
-    let owner = Key::Account(runtime::get_caller());
+    let owner = caller_key();
     write_lp_allowance(&owner, &spender, amount);
 }
 
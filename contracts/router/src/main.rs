@@ -13,14 +13,28 @@ use casper_contract::{
 };
 use casper_types::{
     addressable_entity::{EntityEntryPoint as EntryPoint, EntryPoints},
-    bytesrepr::{FromBytes, ToBytes},
+    bytesrepr::{Bytes, FromBytes, ToBytes},
     contracts::{ContractHash, NamedKeys},
     runtime_args, RuntimeArgs,
-    CLType, CLTyped, CLValue, EntryPointAccess, EntryPointPayment, EntryPointType, Key, Parameter, U256,
+    CLType, CLTyped, CLValue, EntryPointAccess, EntryPointPayment, EntryPointType, Key, Parameter, URef, U256, U512,
 };
+use ectoplasm_common::caller_key;
 
 // Storage keys
 const FACTORY: &str = "factory";
+const ADMIN: &str = "admin";
+const MAX_REFERRAL_BPS: &str = "max_referral_bps";
+const REFERRAL_VOLUME: &str = "referral_volume";
+const REFERRAL_EARNINGS: &str = "referral_earnings";
+const MAX_PRICE_IMPACT_BPS: &str = "max_price_impact_bps";
+const SELF_KEY: &str = "self_key";
+
+// Referral limits
+const BPS_DENOMINATOR: u64 = 10000;
+const REFERRAL_BPS_LIMIT: u64 = 1000; // Admin cannot set a cap above 10%
+
+// Price impact circuit breaker (0 disables the router-wide limit; the admin opts in)
+const DEFAULT_MAX_PRICE_IMPACT_BPS: u64 = 0;
 
 // Error codes
 const ERROR_EXPIRED: u16 = 1;
@@ -31,6 +45,14 @@ const ERROR_EXCESSIVE_INPUT_AMOUNT: u16 = 5;
 const ERROR_INVALID_PATH: u16 = 6;
 const ERROR_PAIR_NOT_FOUND: u16 = 7;
 const ERROR_INSUFFICIENT_LIQUIDITY: u16 = 8;
+const ERROR_INVALID_CALL: u16 = 9;
+const ERROR_UNAUTHORIZED: u16 = 10;
+const ERROR_REFERRAL_TOO_HIGH: u16 = 11;
+const ERROR_ALREADY_INITIALIZED: u16 = 12;
+const ERROR_FAILED_TO_CREATE_DICTIONARY: u16 = 13;
+const ERROR_PRICE_IMPACT_TOO_HIGH: u16 = 14;
+const ERROR_INVALID_PRICE_IMPACT_LIMIT: u16 = 15;
+const ERROR_PRICE_IMPACT_OVERFLOW: u16 = 16;
 
 // ============ Helper Functions ============
 
@@ -40,6 +62,34 @@ fn read_from_uref<T: CLTyped + FromBytes>(name: &str) -> T {
     storage::read(uref).unwrap_or_revert().unwrap_or_revert()
 }
 
+fn write_to_uref<T: CLTyped + ToBytes>(name: &str, value: T) {
+    let key = runtime::get_key(name).unwrap_or_revert();
+    let uref = key.into_uref().unwrap_or_revert();
+    storage::write(uref, value);
+}
+
+fn get_dictionary_uref(name: &str) -> URef {
+    runtime::get_key(name)
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
+}
+
+fn require_admin() {
+    let caller = Key::Account(runtime::get_caller());
+    let admin: Key = read_from_uref(ADMIN);
+    if caller != admin {
+        runtime::revert(casper_types::ApiError::User(ERROR_UNAUTHORIZED));
+    }
+}
+
+/// Revert once the caller's deadline (block time in milliseconds) has passed
+fn ensure_deadline(deadline: u64) {
+    if u64::from(runtime::get_blocktime()) > deadline {
+        runtime::revert(casper_types::ApiError::User(ERROR_EXPIRED));
+    }
+}
+
 fn key_to_str(key: &Key) -> String {
     match key {
         Key::Account(account_hash) => hex_encode(account_hash.as_bytes()),
@@ -76,6 +126,13 @@ fn sort_tokens(token_a: Key, token_b: Key) -> (Key, Key) {
     }
 }
 
+fn referral_key(referrer: &Key, token: &Key) -> String {
+    let mut key = key_to_str(referrer);
+    key.push('_');
+    key.push_str(&key_to_str(token));
+    key
+}
+
 fn get_contract_hash(key: Key) -> ContractHash {
     match key {
         Key::Hash(hash) => ContractHash::new(hash),
@@ -83,6 +140,41 @@ fn get_contract_hash(key: Key) -> ContractHash {
     }
 }
 
+// ============ Call Arguments ============
+
+/// Source of entry point arguments: either the arguments of the current
+/// invocation, or a serialized `RuntimeArgs` supplied to `multicall`.
+enum CallArgs {
+    Runtime,
+    Encoded(RuntimeArgs),
+}
+
+impl CallArgs {
+    fn get<T: CLTyped + FromBytes>(&self, name: &str) -> T {
+        match self {
+            CallArgs::Runtime => runtime::get_named_arg(name),
+            CallArgs::Encoded(args) => args
+                .get(name)
+                .cloned()
+                .unwrap_or_revert_with(casper_types::ApiError::MissingArgument)
+                .into_t()
+                .unwrap_or_revert_with(casper_types::ApiError::InvalidArgument),
+        }
+    }
+
+    /// Like `get`, but returns None when the argument was not supplied.
+    fn try_get<T: CLTyped + FromBytes>(&self, name: &str) -> Option<T> {
+        match self {
+            CallArgs::Runtime => runtime::try_get_named_arg(name),
+            CallArgs::Encoded(args) => args.get(name).cloned().map(|value| {
+                value
+                    .into_t()
+                    .unwrap_or_revert_with(casper_types::ApiError::InvalidArgument)
+            }),
+        }
+    }
+}
+
 // ============ External Contract Calls ============
 
 fn call_factory_get_pair(factory: Key, token_a: Key, token_b: Key) -> Option<Key> {
@@ -206,6 +298,114 @@ fn get_amount_in_internal(amount_out: U256, reserve_in: U256, reserve_out: U256)
     (numerator / denominator) + 1
 }
 
+// ============ Referrals ============
+
+/// Read the optional referral arguments of a swap. Either argument may be
+/// omitted entirely, so callers that predate referrals keep working.
+/// Returns the referrer and share in basis points, or None if no referral applies.
+fn read_referral(args: &CallArgs) -> Option<(Key, u64)> {
+    let referrer: Option<Key> = args.try_get::<Option<Key>>("referrer").flatten();
+    let referral_bps: Option<u64> = args.try_get::<Option<u64>>("referral_bps").flatten();
+
+    match (referrer, referral_bps) {
+        (Some(referrer), Some(bps)) if bps > 0 => {
+            let max_bps: u64 = read_from_uref(MAX_REFERRAL_BPS);
+            if bps > max_bps {
+                runtime::revert(casper_types::ApiError::User(ERROR_REFERRAL_TOO_HIGH));
+            }
+            Some((referrer, bps))
+        }
+        _ => None,
+    }
+}
+
+fn referral_fee(amount: U256, referral_bps: u64) -> U256 {
+    (amount * U256::from(referral_bps)) / U256::from(BPS_DENOMINATOR)
+}
+
+/// Pay the referrer their share of the input token and update their stats
+fn pay_referral(token: Key, sender: Key, referrer: Key, volume: U256, fee: U256) {
+    if !fee.is_zero() {
+        call_token_transfer_from(token, sender, referrer, fee);
+    }
+
+    let stats_key = referral_key(&referrer, &token);
+
+    let volume_uref = get_dictionary_uref(REFERRAL_VOLUME);
+    let total_volume: U256 = storage::dictionary_get(volume_uref, &stats_key)
+        .unwrap_or_default()
+        .unwrap_or_default();
+    storage::dictionary_put(volume_uref, &stats_key, total_volume + volume);
+
+    let earnings_uref = get_dictionary_uref(REFERRAL_EARNINGS);
+    let total_earnings: U256 = storage::dictionary_get(earnings_uref, &stats_key)
+        .unwrap_or_default()
+        .unwrap_or_default();
+    storage::dictionary_put(earnings_uref, &stats_key, total_earnings + fee);
+}
+
+// ============ Price Impact ============
+
+/// Resolve the price impact limit for a swap. A limit of 0 means "no limit",
+/// both router-wide and per call. A per-call override may only tighten the
+/// router-wide limit: while that limit is 0 any override (including 0) is
+/// accepted, otherwise it must lie in 1..=limit. The override may be omitted.
+/// Returns None when no limit applies.
+fn read_price_impact_limit(args: &CallArgs) -> Option<u64> {
+    let global_bps: u64 = read_from_uref(MAX_PRICE_IMPACT_BPS);
+    let override_bps: Option<u64> = args.try_get::<Option<u64>>("max_price_impact_bps").flatten();
+
+    let limit_bps = match override_bps {
+        Some(bps) if global_bps != 0 && (bps == 0 || bps > global_bps) => {
+            runtime::revert(casper_types::ApiError::User(ERROR_INVALID_PRICE_IMPACT_LIMIT));
+        }
+        Some(bps) => bps,
+        None => global_bps,
+    };
+
+    if limit_bps == 0 {
+        None
+    } else {
+        Some(limit_bps)
+    }
+}
+
+fn to_u512(value: U256) -> U512 {
+    let mut bytes = [0u8; 32];
+    value.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
+
+/// How far a single hop moves the pair's mid price, in basis points.
+/// Mid price of the output token is reserve_in / reserve_out before and
+/// (reserve_in + amount_in) / (reserve_out - amount_out) after the swap.
+/// Computed in U512; reverts if even that overflows.
+fn price_impact_bps(amount_in: U256, amount_out: U256, reserve_in: U256, reserve_out: U256) -> U512 {
+    if reserve_in.is_zero() || amount_out >= reserve_out {
+        return U512::MAX;
+    }
+    let bps = U512::from(BPS_DENOMINATOR);
+    let numerator = (to_u512(reserve_in) + to_u512(amount_in))
+        .checked_mul(to_u512(reserve_out))
+        .and_then(|value| value.checked_mul(bps))
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_PRICE_IMPACT_OVERFLOW));
+    let denominator = to_u512(reserve_in) * to_u512(reserve_out - amount_out);
+    let price_after = numerator / denominator;
+    if price_after > bps {
+        price_after - bps
+    } else {
+        U512::zero()
+    }
+}
+
+fn check_price_impact(limit_bps: Option<u64>, amount_in: U256, amount_out: U256, reserve_in: U256, reserve_out: U256) {
+    if let Some(limit_bps) = limit_bps {
+        if price_impact_bps(amount_in, amount_out, reserve_in, reserve_out) > U512::from(limit_bps) {
+            runtime::revert(casper_types::ApiError::User(ERROR_PRICE_IMPACT_TOO_HIGH));
+        }
+    }
+}
+
 /// Get reserves for a pair, sorted by token order
 fn get_reserves_sorted(factory: Key, token_a: Key, token_b: Key) -> (U256, U256) {
     let pair = call_factory_get_pair(factory, token_a, token_b);
@@ -257,6 +457,91 @@ fn calculate_liquidity_amounts(
 
 // ============ Entry Points ============
 
+/// Initialize referral dictionaries. Called after contract creation.
+#[no_mangle]
+pub extern "C" fn init() {
+    if runtime::get_key(REFERRAL_VOLUME).is_some() {
+        runtime::revert(casper_types::ApiError::User(ERROR_ALREADY_INITIALIZED));
+    }
+
+    // Key this contract is known by to tokens and pairs
+    let self_key: Key = runtime::get_named_arg("self_key");
+    runtime::put_key(SELF_KEY, storage::new_uref(self_key).into());
+
+    storage::new_dictionary(REFERRAL_VOLUME)
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_FAILED_TO_CREATE_DICTIONARY));
+    storage::new_dictionary(REFERRAL_EARNINGS)
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_FAILED_TO_CREATE_DICTIONARY));
+}
+
+#[no_mangle]
+pub extern "C" fn admin() {
+    let admin: Key = read_from_uref(ADMIN);
+    runtime::ret(CLValue::from_t(admin).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn max_referral_bps() {
+    let max_bps: u64 = read_from_uref(MAX_REFERRAL_BPS);
+    runtime::ret(CLValue::from_t(max_bps).unwrap_or_revert());
+}
+
+/// Set the maximum referral share a swap may request (admin only)
+#[no_mangle]
+pub extern "C" fn set_max_referral_bps() {
+    require_admin();
+
+    let max_bps: u64 = runtime::get_named_arg("max_bps");
+    if max_bps > REFERRAL_BPS_LIMIT {
+        runtime::revert(casper_types::ApiError::User(ERROR_REFERRAL_TOO_HIGH));
+    }
+
+    write_to_uref(MAX_REFERRAL_BPS, max_bps);
+}
+
+#[no_mangle]
+pub extern "C" fn max_price_impact_bps() {
+    let max_bps: u64 = read_from_uref(MAX_PRICE_IMPACT_BPS);
+    runtime::ret(CLValue::from_t(max_bps).unwrap_or_revert());
+}
+
+/// Set the maximum mid price move allowed per swap hop (admin only, 0 disables).
+/// Swaps may pass a tighter `max_price_impact_bps`; see `read_price_impact_limit`
+#[no_mangle]
+pub extern "C" fn set_max_price_impact_bps() {
+    require_admin();
+
+    let max_bps: u64 = runtime::get_named_arg("max_bps");
+    write_to_uref(MAX_PRICE_IMPACT_BPS, max_bps);
+}
+
+/// Transfer the admin role to a new account (admin only)
+#[no_mangle]
+pub extern "C" fn transfer_admin() {
+    require_admin();
+
+    let new_admin: Key = runtime::get_named_arg("new_admin");
+    write_to_uref(ADMIN, new_admin);
+}
+
+/// Get cumulative referral stats for a referrer in a given input token.
+/// Returns (volume, earnings).
+#[no_mangle]
+pub extern "C" fn get_referral_stats() {
+    let referrer: Key = runtime::get_named_arg("referrer");
+    let token: Key = runtime::get_named_arg("token");
+    let stats_key = referral_key(&referrer, &token);
+
+    let volume: U256 = storage::dictionary_get(get_dictionary_uref(REFERRAL_VOLUME), &stats_key)
+        .unwrap_or_default()
+        .unwrap_or_default();
+    let earnings: U256 = storage::dictionary_get(get_dictionary_uref(REFERRAL_EARNINGS), &stats_key)
+        .unwrap_or_default()
+        .unwrap_or_default();
+
+    runtime::ret(CLValue::from_t((volume, earnings)).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn factory() {
     let factory: Key = read_from_uref(FACTORY);
@@ -290,11 +575,10 @@ pub extern "C" fn get_amount_in() {
     runtime::ret(CLValue::from_t(result).unwrap_or_revert());
 }
 
-#[no_mangle]
-pub extern "C" fn get_amounts_out() {
+fn get_amounts_out_with(args: &CallArgs) -> Vec<U256> {
     let factory: Key = read_from_uref(FACTORY);
-    let amount_in: U256 = runtime::get_named_arg("amount_in");
-    let path: Vec<Key> = runtime::get_named_arg("path");
+    let amount_in: U256 = args.get("amount_in");
+    let path: Vec<Key> = args.get("path");
 
     if path.len() < 2 {
         runtime::revert(casper_types::ApiError::User(ERROR_INVALID_PATH));
@@ -306,14 +590,19 @@ pub extern "C" fn get_amounts_out() {
         amounts.push(get_amount_out_internal(amounts[i], reserve_in, reserve_out));
     }
 
-    runtime::ret(CLValue::from_t(amounts).unwrap_or_revert());
+    amounts
 }
 
 #[no_mangle]
-pub extern "C" fn get_amounts_in() {
+pub extern "C" fn get_amounts_out() {
+    let result = get_amounts_out_with(&CallArgs::Runtime);
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+fn get_amounts_in_with(args: &CallArgs) -> Vec<U256> {
     let factory: Key = read_from_uref(FACTORY);
-    let amount_out: U256 = runtime::get_named_arg("amount_out");
-    let path: Vec<Key> = runtime::get_named_arg("path");
+    let amount_out: U256 = args.get("amount_out");
+    let path: Vec<Key> = args.get("path");
 
     if path.len() < 2 {
         runtime::revert(casper_types::ApiError::User(ERROR_INVALID_PATH));
@@ -327,22 +616,26 @@ pub extern "C" fn get_amounts_in() {
         amounts[i - 1] = get_amount_in_internal(amounts[i], reserve_in, reserve_out);
     }
 
-    runtime::ret(CLValue::from_t(amounts).unwrap_or_revert());
+    amounts
 }
 
 #[no_mangle]
-pub extern "C" fn add_liquidity() {
-    let factory: Key = read_from_uref(FACTORY);
-    let token_a: Key = runtime::get_named_arg("token_a");
-    let token_b: Key = runtime::get_named_arg("token_b");
-    let amount_a_desired: U256 = runtime::get_named_arg("amount_a_desired");
-    let amount_b_desired: U256 = runtime::get_named_arg("amount_b_desired");
-    let amount_a_min: U256 = runtime::get_named_arg("amount_a_min");
-    let amount_b_min: U256 = runtime::get_named_arg("amount_b_min");
-    let to: Key = runtime::get_named_arg("to");
-    let deadline: u64 = runtime::get_named_arg("deadline");
+pub extern "C" fn get_amounts_in() {
+    let result = get_amounts_in_with(&CallArgs::Runtime);
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
 
-    // Note: deadline check would need block timestamp access
+fn add_liquidity_with(args: &CallArgs) -> (U256, U256, U256) {
+    let factory: Key = read_from_uref(FACTORY);
+    let token_a: Key = args.get("token_a");
+    let token_b: Key = args.get("token_b");
+    let amount_a_desired: U256 = args.get("amount_a_desired");
+    let amount_b_desired: U256 = args.get("amount_b_desired");
+    let amount_a_min: U256 = args.get("amount_a_min");
+    let amount_b_min: U256 = args.get("amount_b_min");
+    let to: Key = args.get("to");
+    let deadline: u64 = args.get("deadline");
+    ensure_deadline(deadline);
 
     // Get pair
     let pair = call_factory_get_pair(factory, token_a, token_b);
@@ -363,26 +656,32 @@ pub extern "C" fn add_liquidity() {
     );
 
     // Transfer tokens from sender to pair
-    let sender = Key::Account(runtime::get_caller());
+    let sender = caller_key();
     call_token_transfer_from(token_a, sender, pair, amount_a);
     call_token_transfer_from(token_b, sender, pair, amount_b);
 
     // Mint LP tokens
     let liquidity = call_pair_mint(pair, to);
 
-    runtime::ret(CLValue::from_t((amount_a, amount_b, liquidity)).unwrap_or_revert());
+    (amount_a, amount_b, liquidity)
 }
 
 #[no_mangle]
-pub extern "C" fn remove_liquidity() {
+pub extern "C" fn add_liquidity() {
+    let result = add_liquidity_with(&CallArgs::Runtime);
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+fn remove_liquidity_with(args: &CallArgs) -> (U256, U256) {
     let factory: Key = read_from_uref(FACTORY);
-    let token_a: Key = runtime::get_named_arg("token_a");
-    let token_b: Key = runtime::get_named_arg("token_b");
-    let liquidity: U256 = runtime::get_named_arg("liquidity");
-    let amount_a_min: U256 = runtime::get_named_arg("amount_a_min");
-    let amount_b_min: U256 = runtime::get_named_arg("amount_b_min");
-    let to: Key = runtime::get_named_arg("to");
-    let deadline: u64 = runtime::get_named_arg("deadline");
+    let token_a: Key = args.get("token_a");
+    let token_b: Key = args.get("token_b");
+    let liquidity: U256 = args.get("liquidity");
+    let amount_a_min: U256 = args.get("amount_a_min");
+    let amount_b_min: U256 = args.get("amount_b_min");
+    let to: Key = args.get("to");
+    let deadline: u64 = args.get("deadline");
+    ensure_deadline(deadline);
 
     // Get pair
     let pair = call_factory_get_pair(factory, token_a, token_b);
@@ -392,7 +691,7 @@ pub extern "C" fn remove_liquidity() {
     let pair = pair.unwrap_or_revert();
 
     // Transfer LP tokens from sender to pair
-    let sender = Key::Account(runtime::get_caller());
+    let sender = caller_key();
     call_token_transfer_from(pair, sender, pair, liquidity);
 
     // Burn LP tokens and receive underlying tokens
@@ -413,35 +712,126 @@ pub extern "C" fn remove_liquidity() {
         runtime::revert(casper_types::ApiError::User(ERROR_INSUFFICIENT_B_AMOUNT));
     }
 
-    runtime::ret(CLValue::from_t((amount_a, amount_b)).unwrap_or_revert());
+    (amount_a, amount_b)
 }
 
 #[no_mangle]
-pub extern "C" fn swap_exact_tokens_for_tokens() {
+pub extern "C" fn remove_liquidity() {
+    let result = remove_liquidity_with(&CallArgs::Runtime);
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+fn remove_liquidity_one_token_with(args: &CallArgs) -> U256 {
     let factory: Key = read_from_uref(FACTORY);
-    let amount_in: U256 = runtime::get_named_arg("amount_in");
-    let amount_out_min: U256 = runtime::get_named_arg("amount_out_min");
-    let path: Vec<Key> = runtime::get_named_arg("path");
-    let to: Key = runtime::get_named_arg("to");
-    let deadline: u64 = runtime::get_named_arg("deadline");
+    let token_a: Key = args.get("token_a");
+    let token_b: Key = args.get("token_b");
+    let liquidity: U256 = args.get("liquidity");
+    let token_out: Key = args.get("token_out");
+    let amount_out_min: U256 = args.get("amount_out_min");
+    let to: Key = args.get("to");
+    let deadline: u64 = args.get("deadline");
+    ensure_deadline(deadline);
+
+    // token_out must be one side of the pair; the other side is swapped into it
+    let token_in = if key_to_str(&token_out) == key_to_str(&token_a) {
+        token_b
+    } else if key_to_str(&token_out) == key_to_str(&token_b) {
+        token_a
+    } else {
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_PATH));
+    };
+
+    // Get pair
+    let pair = call_factory_get_pair(factory, token_a, token_b);
+    if pair.is_none() {
+        runtime::revert(casper_types::ApiError::User(ERROR_PAIR_NOT_FOUND));
+    }
+    let pair = pair.unwrap_or_revert();
+
+    // Transfer LP tokens from sender to pair and burn them to the router,
+    // which routes each side on without needing any token allowances
+    let sender = caller_key();
+    let router: Key = read_from_uref(SELF_KEY);
+    call_token_transfer_from(pair, sender, pair, liquidity);
+    let (amount0, amount1) = call_pair_burn(pair, router);
+
+    // Sort amounts according to token order
+    let token0 = call_pair_token0(pair);
+    let out_is_token0 = key_to_str(&token_out) == key_to_str(&token0);
+    let (amount_kept, amount_to_swap) = if out_is_token0 {
+        (amount0, amount1)
+    } else {
+        (amount1, amount0)
+    };
+
+    // Swap the unwanted side back through the same pair at post-burn reserves
+    let (reserve_in, reserve_out) = get_reserves_sorted(factory, token_in, token_out);
+    let amount_swapped = get_amount_out_internal(amount_to_swap, reserve_in, reserve_out);
+    let amount_out = amount_kept + amount_swapped;
+
+    if amount_out < amount_out_min {
+        runtime::revert(casper_types::ApiError::User(ERROR_INSUFFICIENT_OUTPUT_AMOUNT));
+    }
+
+    call_token_transfer(token_in, pair, amount_to_swap);
+    let (amount0_out, amount1_out) = if out_is_token0 {
+        (amount_swapped, U256::zero())
+    } else {
+        (U256::zero(), amount_swapped)
+    };
+    call_pair_swap(pair, amount0_out, amount1_out, to);
+
+    // Forward the directly withdrawn side to the recipient
+    call_token_transfer(token_out, to, amount_kept);
+
+    amount_out
+}
+
+/// Remove liquidity and receive the whole position in a single token
+#[no_mangle]
+pub extern "C" fn remove_liquidity_one_token() {
+    let result = remove_liquidity_one_token_with(&CallArgs::Runtime);
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+fn swap_exact_tokens_for_tokens_with(args: &CallArgs) -> Vec<U256> {
+    let factory: Key = read_from_uref(FACTORY);
+    let amount_in: U256 = args.get("amount_in");
+    let amount_out_min: U256 = args.get("amount_out_min");
+    let path: Vec<Key> = args.get("path");
+    let to: Key = args.get("to");
+    let deadline: u64 = args.get("deadline");
+    ensure_deadline(deadline);
+    let referral = read_referral(args);
+    let impact_limit = read_price_impact_limit(args);
 
     if path.len() < 2 {
         runtime::revert(casper_types::ApiError::User(ERROR_INVALID_PATH));
     }
 
+    // The referral share is taken from the input before it reaches the pair
+    let fee = match referral {
+        Some((_, referral_bps)) => referral_fee(amount_in, referral_bps),
+        None => U256::zero(),
+    };
+
     // Calculate amounts
-    let mut amounts = vec![amount_in];
+    let mut amounts = vec![amount_in - fee];
     for i in 0..(path.len() - 1) {
         let (reserve_in, reserve_out) = get_reserves_sorted(factory, path[i], path[i + 1]);
         amounts.push(get_amount_out_internal(amounts[i], reserve_in, reserve_out));
+        check_price_impact(impact_limit, amounts[i], amounts[i + 1], reserve_in, reserve_out);
     }
 
     if amounts[amounts.len() - 1] < amount_out_min {
         runtime::revert(casper_types::ApiError::User(ERROR_INSUFFICIENT_OUTPUT_AMOUNT));
     }
 
-    // Transfer input tokens from sender to first pair
-    let sender = Key::Account(runtime::get_caller());
+    // Pay referrer, then transfer input tokens from sender to first pair
+    let sender = caller_key();
+    if let Some((referrer, _)) = referral {
+        pay_referral(path[0], sender, referrer, amounts[0] + fee, fee);
+    }
     let first_pair = call_factory_get_pair(factory, path[0], path[1]).unwrap_or_revert();
     call_token_transfer_from(path[0], sender, first_pair, amounts[0]);
 
@@ -468,17 +858,25 @@ pub extern "C" fn swap_exact_tokens_for_tokens() {
         call_pair_swap(pair, amount0_out, amount1_out, recipient);
     }
 
-    runtime::ret(CLValue::from_t(amounts).unwrap_or_revert());
+    amounts
 }
 
 #[no_mangle]
-pub extern "C" fn swap_tokens_for_exact_tokens() {
+pub extern "C" fn swap_exact_tokens_for_tokens() {
+    let result = swap_exact_tokens_for_tokens_with(&CallArgs::Runtime);
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+fn swap_tokens_for_exact_tokens_with(args: &CallArgs) -> Vec<U256> {
     let factory: Key = read_from_uref(FACTORY);
-    let amount_out: U256 = runtime::get_named_arg("amount_out");
-    let amount_in_max: U256 = runtime::get_named_arg("amount_in_max");
-    let path: Vec<Key> = runtime::get_named_arg("path");
-    let to: Key = runtime::get_named_arg("to");
-    let deadline: u64 = runtime::get_named_arg("deadline");
+    let amount_out: U256 = args.get("amount_out");
+    let amount_in_max: U256 = args.get("amount_in_max");
+    let path: Vec<Key> = args.get("path");
+    let to: Key = args.get("to");
+    let deadline: u64 = args.get("deadline");
+    ensure_deadline(deadline);
+    let referral = read_referral(args);
+    let impact_limit = read_price_impact_limit(args);
 
     if path.len() < 2 {
         runtime::revert(casper_types::ApiError::User(ERROR_INVALID_PATH));
@@ -491,14 +889,24 @@ pub extern "C" fn swap_tokens_for_exact_tokens() {
     for i in (1..path.len()).rev() {
         let (reserve_in, reserve_out) = get_reserves_sorted(factory, path[i - 1], path[i]);
         amounts[i - 1] = get_amount_in_internal(amounts[i], reserve_in, reserve_out);
+        check_price_impact(impact_limit, amounts[i - 1], amounts[i], reserve_in, reserve_out);
     }
 
-    if amounts[0] > amount_in_max {
+    // The referral share is charged on top of the input the pair requires
+    let fee = match referral {
+        Some((_, referral_bps)) => referral_fee(amounts[0], referral_bps),
+        None => U256::zero(),
+    };
+
+    if amounts[0] + fee > amount_in_max {
         runtime::revert(casper_types::ApiError::User(ERROR_EXCESSIVE_INPUT_AMOUNT));
     }
 
-    // Transfer input tokens from sender to first pair
-    let sender = Key::Account(runtime::get_caller());
+    // Pay referrer, then transfer input tokens from sender to first pair
+    let sender = caller_key();
+    if let Some((referrer, _)) = referral {
+        pay_referral(path[0], sender, referrer, amounts[0] + fee, fee);
+    }
     let first_pair = call_factory_get_pair(factory, path[0], path[1]).unwrap_or_revert();
     call_token_transfer_from(path[0], sender, first_pair, amounts[0]);
 
@@ -524,7 +932,50 @@ pub extern "C" fn swap_tokens_for_exact_tokens() {
         call_pair_swap(pair, amount0_out, amount1_out, recipient);
     }
 
-    runtime::ret(CLValue::from_t(amounts).unwrap_or_revert());
+    amounts
+}
+
+#[no_mangle]
+pub extern "C" fn swap_tokens_for_exact_tokens() {
+    let result = swap_tokens_for_exact_tokens_with(&CallArgs::Runtime);
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+/// Serialize an entry point result for `multicall`
+fn encode_result<T: ToBytes>(result: T) -> Bytes {
+    Bytes::from(result.to_bytes().unwrap_or_revert())
+}
+
+/// Execute a batch of router calls in sequence within a single deploy.
+/// Each call is `(entry_point, serialized RuntimeArgs)`; if any call reverts,
+/// the whole batch reverts. Returns the serialized result of every call.
+#[no_mangle]
+pub extern "C" fn multicall() {
+    let calls: Vec<(String, Bytes)> = runtime::get_named_arg("calls");
+
+    let mut results: Vec<Bytes> = Vec::with_capacity(calls.len());
+    for (entry_point, encoded_args) in calls {
+        let (call_args, remainder) = RuntimeArgs::from_bytes(encoded_args.as_slice())
+            .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_INVALID_CALL));
+        if !remainder.is_empty() {
+            runtime::revert(casper_types::ApiError::User(ERROR_INVALID_CALL));
+        }
+        let args = CallArgs::Encoded(call_args);
+
+        let result = match entry_point.as_str() {
+            "get_amounts_out" => encode_result(get_amounts_out_with(&args)),
+            "get_amounts_in" => encode_result(get_amounts_in_with(&args)),
+            "add_liquidity" => encode_result(add_liquidity_with(&args)),
+            "remove_liquidity" => encode_result(remove_liquidity_with(&args)),
+            "remove_liquidity_one_token" => encode_result(remove_liquidity_one_token_with(&args)),
+            "swap_exact_tokens_for_tokens" => encode_result(swap_exact_tokens_for_tokens_with(&args)),
+            "swap_tokens_for_exact_tokens" => encode_result(swap_tokens_for_exact_tokens_with(&args)),
+            _ => runtime::revert(casper_types::ApiError::User(ERROR_INVALID_CALL)),
+        };
+        results.push(result);
+    }
+
+    runtime::ret(CLValue::from_t(results).unwrap_or_revert());
 }
 
 // ============ Contract Installation ============
@@ -532,7 +983,42 @@ pub extern "C" fn swap_tokens_for_exact_tokens() {
 fn get_entry_points() -> EntryPoints {
     let mut ep = EntryPoints::new();
 
+    ep.add_entry_point(EntryPoint::new("init", vec![Parameter::new("self_key", CLType::Key)], CLType::Unit, EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller));
     ep.add_entry_point(EntryPoint::new("factory", vec![], CLType::Key, EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller));
+    ep.add_entry_point(EntryPoint::new("admin", vec![], CLType::Key, EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller));
+    ep.add_entry_point(EntryPoint::new("max_referral_bps", vec![], CLType::U64, EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller));
+    ep.add_entry_point(EntryPoint::new("max_price_impact_bps", vec![], CLType::U64, EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller));
+
+    ep.add_entry_point(EntryPoint::new(
+        "set_max_referral_bps",
+        vec![Parameter::new("max_bps", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
+    ));
+
+    ep.add_entry_point(EntryPoint::new(
+        "set_max_price_impact_bps",
+        vec![Parameter::new("max_bps", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
+    ));
+
+    ep.add_entry_point(EntryPoint::new(
+        "transfer_admin",
+        vec![Parameter::new("new_admin", CLType::Key)],
+        CLType::Unit,
+        EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
+    ));
+
+    ep.add_entry_point(EntryPoint::new(
+        "get_referral_stats",
+        vec![
+            Parameter::new("referrer", CLType::Key),
+            Parameter::new("token", CLType::Key),
+        ],
+        CLType::Tuple2([Box::new(CLType::U256), Box::new(CLType::U256)]),
+        EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
+    ));
 
     ep.add_entry_point(EntryPoint::new(
         "quote",
@@ -618,6 +1104,21 @@ fn get_entry_points() -> EntryPoints {
         EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
     ));
 
+    ep.add_entry_point(EntryPoint::new(
+        "remove_liquidity_one_token",
+        vec![
+            Parameter::new("token_a", CLType::Key),
+            Parameter::new("token_b", CLType::Key),
+            Parameter::new("liquidity", CLType::U256),
+            Parameter::new("token_out", CLType::Key),
+            Parameter::new("amount_out_min", CLType::U256),
+            Parameter::new("to", CLType::Key),
+            Parameter::new("deadline", CLType::U64),
+        ],
+        CLType::U256,
+        EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
+    ));
+
     ep.add_entry_point(EntryPoint::new(
         "swap_exact_tokens_for_tokens",
         vec![
@@ -626,6 +1127,9 @@ fn get_entry_points() -> EntryPoints {
             Parameter::new("path", CLType::List(Box::new(CLType::Key))),
             Parameter::new("to", CLType::Key),
             Parameter::new("deadline", CLType::U64),
+            Parameter::new("referrer", CLType::Option(Box::new(CLType::Key))),
+            Parameter::new("referral_bps", CLType::Option(Box::new(CLType::U64))),
+            Parameter::new("max_price_impact_bps", CLType::Option(Box::new(CLType::U64))),
         ],
         CLType::List(Box::new(CLType::U256)),
         EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
@@ -639,11 +1143,26 @@ fn get_entry_points() -> EntryPoints {
             Parameter::new("path", CLType::List(Box::new(CLType::Key))),
             Parameter::new("to", CLType::Key),
             Parameter::new("deadline", CLType::U64),
+            Parameter::new("referrer", CLType::Option(Box::new(CLType::Key))),
+            Parameter::new("referral_bps", CLType::Option(Box::new(CLType::U64))),
+            Parameter::new("max_price_impact_bps", CLType::Option(Box::new(CLType::U64))),
         ],
         CLType::List(Box::new(CLType::U256)),
         EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
     ));
 
+    ep.add_entry_point(EntryPoint::new(
+        "multicall",
+        vec![
+            Parameter::new(
+                "calls",
+                CLType::List(Box::new(CLType::Tuple2([Box::new(CLType::String), Box::new(Bytes::cl_type())]))),
+            ),
+        ],
+        CLType::List(Box::new(Bytes::cl_type())),
+        EntryPointAccess::Public, EntryPointType::Called, EntryPointPayment::Caller,
+    ));
+
     ep
 }
 
@@ -651,8 +1170,13 @@ fn get_entry_points() -> EntryPoints {
 pub extern "C" fn call() {
     let factory: Key = runtime::get_named_arg("factory");
 
+    let admin = Key::Account(runtime::get_caller());
+
     let mut named_keys = NamedKeys::new();
     named_keys.insert(FACTORY.to_string(), storage::new_uref(factory).into());
+    named_keys.insert(ADMIN.to_string(), storage::new_uref(admin).into());
+    named_keys.insert(MAX_REFERRAL_BPS.to_string(), storage::new_uref(0u64).into());
+    named_keys.insert(MAX_PRICE_IMPACT_BPS.to_string(), storage::new_uref(DEFAULT_MAX_PRICE_IMPACT_BPS).into());
 
     let (contract_hash, _) = storage::new_contract(
         get_entry_points(),
@@ -663,4 +1187,13 @@ pub extern "C" fn call() {
     );
 
     runtime::put_key("ectoplasm_router_contract", contract_hash.into());
+
+    // Call init to create dictionaries in contract context
+    runtime::call_contract::<()>(
+        contract_hash,
+        "init",
+        runtime_args! {
+            "self_key" => Key::Hash(contract_hash.value())
+        },
+    );
 }
@@ -26,14 +26,27 @@ const PLATFORM_WALLET: &str = "platform_wallet";
 const LAUNCHES: &str = "launches";
 const LAUNCHES_META: &str = "launches_meta";
 const TOKEN_TO_LAUNCH: &str = "token_to_launch";
+const LAUNCH_PROTECTIONS: &str = "launch_protections";
+const LAUNCH_PRESALES: &str = "launch_presales";
+const LAUNCH_CURVE_POINTS: &str = "launch_curve_points";
+const LAUNCH_VESTING: &str = "launch_vesting";
+const LAUNCH_INITIAL_BUYS: &str = "launch_initial_buys";
+const LAUNCH_SCHEDULES: &str = "launch_schedules";
+const LAUNCH_QUOTE_TOKENS: &str = "launch_quote_tokens";
 const LAUNCH_COUNT: &str = "launch_count";
 const INITIALIZED: &str = "initialized";
 
+// Curve types (mirrors bonding-curve `CurveType`)
+const CURVE_TYPE_MAX: u8 = 5;
+const CURVE_TYPE_PIECEWISE: u8 = 5;
+const MAX_CONTROL_POINTS: usize = 16;
+
 // Default curve parameters
 const DEFAULT_BASE_PRICE: u64 = 1_000_000_000; // 1 CSPR per token
 const DEFAULT_MAX_PRICE: u64 = 100_000_000_000; // 100 CSPR per token
 const DEFAULT_TOTAL_SUPPLY: u128 = 1_000_000_000_000_000_000_000_000; // 1 million tokens (18 decimals)
 const TOKEN_DECIMALS: u8 = 18;
+const DEFAULT_MAX_CREATOR_ALLOCATION_BPS: u64 = 1000; // used when the controller is unreachable
 
 // ============ Error Codes ============
 
@@ -45,12 +58,22 @@ const ERROR_INVALID_NAME: u16 = 5;
 const ERROR_LAUNCH_NOT_FOUND: u16 = 6;
 const ERROR_INDEX_OUT_OF_BOUNDS: u16 = 7;
 const ERROR_FAILED_TO_CREATE_DICTIONARY: u16 = 8;
+const ERROR_INVALID_PROTECTION: u16 = 9;
+const ERROR_INVALID_PRESALE: u16 = 10;
+const ERROR_INVALID_CURVE_POINTS: u16 = 11;
+const ERROR_ALLOCATION_TOO_HIGH: u16 = 12;
+const ERROR_INVALID_INITIAL_BUY: u16 = 13;
+const ERROR_INVALID_START_TIME: u16 = 14;
+const ERROR_UNAUTHORIZED: u16 = 15;
+const ERROR_ALREADY_STARTED: u16 = 16;
+const ERROR_INVALID_QUOTE_TOKEN: u16 = 17;
 
 // ============ Launch Status ============
 
 const STATUS_ACTIVE: u8 = 0;
 const STATUS_GRADUATED: u8 = 1;
 const STATUS_REFUNDING: u8 = 2;
+const STATUS_CANCELLED: u8 = 3;
 
 // ============ Helper Functions ============
 
@@ -102,6 +125,60 @@ fn get_current_time() -> u64 {
     runtime::get_blocktime().into()
 }
 
+/// Piecewise control points must span 0..=10000 bps with strictly increasing
+/// progress and non-zero, non-decreasing prices
+fn valid_control_points(points: &[(u64, U512)]) -> bool {
+    if points.len() < 2 || points.len() > MAX_CONTROL_POINTS {
+        return false;
+    }
+    if points[0].0 != 0 || points[points.len() - 1].0 != 10000 || points[0].1.is_zero() {
+        return false;
+    }
+    points
+        .windows(2)
+        .all(|window| window[1].0 > window[0].0 && window[1].1 >= window[0].1)
+}
+
+/// Start time and deadline of a launch; launches without a schedule opened at creation
+fn read_schedule(launch_id: u64) -> Option<(u64, u64)> {
+    let schedules_uref = get_dictionary_uref(LAUNCH_SCHEDULES);
+    storage::dictionary_get(schedules_uref, &launch_id.to_string()).unwrap_or_default()
+}
+
+fn is_upcoming(launch_id: u64, now: u64) -> bool {
+    read_schedule(launch_id).map_or(false, |(start_time, _)| start_time > now)
+}
+
+/// Only the creator may change a launch, and only while it is active and not yet open
+/// Returns the launch's (start_time, deadline)
+fn require_creator_before_start(launch_id: u64) -> (u64, u64) {
+    let count: u64 = read_from_uref(LAUNCH_COUNT);
+    if launch_id >= count {
+        runtime::revert(casper_types::ApiError::User(ERROR_INDEX_OUT_OF_BOUNDS));
+    }
+
+    let launches_uref = get_dictionary_uref(LAUNCHES);
+    let (_, _, creator): (Key, Key, Key) =
+        storage::dictionary_get(launches_uref, &launch_id.to_string())
+            .unwrap_or_default()
+            .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_LAUNCH_NOT_FOUND));
+    if Key::Account(runtime::get_caller()) != creator {
+        runtime::revert(casper_types::ApiError::User(ERROR_UNAUTHORIZED));
+    }
+
+    let meta_uref = get_dictionary_uref(LAUNCHES_META);
+    let (_, _, (_, status, _)): (String, String, (u8, u8, u64)) =
+        storage::dictionary_get(meta_uref, &launch_id.to_string())
+            .unwrap_or_default()
+            .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_LAUNCH_NOT_FOUND));
+    let (start_time, deadline) = read_schedule(launch_id)
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_ALREADY_STARTED));
+    if status != STATUS_ACTIVE || start_time <= get_current_time() {
+        runtime::revert(casper_types::ApiError::User(ERROR_ALREADY_STARTED));
+    }
+    (start_time, deadline)
+}
+
 // ============ Entry Points ============
 
 /// Initialize the factory (creates dictionaries)
@@ -119,6 +196,20 @@ pub extern "C" fn init() {
         .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_FAILED_TO_CREATE_DICTIONARY));
     storage::new_dictionary(TOKEN_TO_LAUNCH)
         .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_FAILED_TO_CREATE_DICTIONARY));
+    storage::new_dictionary(LAUNCH_PROTECTIONS)
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_FAILED_TO_CREATE_DICTIONARY));
+    storage::new_dictionary(LAUNCH_PRESALES)
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_FAILED_TO_CREATE_DICTIONARY));
+    storage::new_dictionary(LAUNCH_CURVE_POINTS)
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_FAILED_TO_CREATE_DICTIONARY));
+    storage::new_dictionary(LAUNCH_VESTING)
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_FAILED_TO_CREATE_DICTIONARY));
+    storage::new_dictionary(LAUNCH_INITIAL_BUYS)
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_FAILED_TO_CREATE_DICTIONARY));
+    storage::new_dictionary(LAUNCH_SCHEDULES)
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_FAILED_TO_CREATE_DICTIONARY));
+    storage::new_dictionary(LAUNCH_QUOTE_TOKENS)
+        .unwrap_or_revert_with(casper_types::ApiError::User(ERROR_FAILED_TO_CREATE_DICTIONARY));
 
     write_to_uref(INITIALIZED, true);
 }
@@ -179,6 +270,128 @@ pub extern "C" fn get_launch_meta() {
     runtime::ret(CLValue::from_t(meta_data).unwrap_or_revert());
 }
 
+/// Get anti-sniping protections by launch ID, as passed to the bonding curve
+/// Returns nested tuple: (max_wallet_cspr, (snipe_window, max_holding_bps, buy_cooldown))
+#[no_mangle]
+pub extern "C" fn get_launch_protections() {
+    let launch_id: u64 = runtime::get_named_arg("launch_id");
+    let count: u64 = read_from_uref(LAUNCH_COUNT);
+
+    if launch_id >= count {
+        runtime::revert(casper_types::ApiError::User(ERROR_INDEX_OUT_OF_BOUNDS));
+    }
+
+    let protections_uref = get_dictionary_uref(LAUNCH_PROTECTIONS);
+    let protections: Option<(U512, (u64, u64, u64))> =
+        storage::dictionary_get(protections_uref, &launch_id.to_string()).unwrap_or_default();
+
+    runtime::ret(CLValue::from_t(protections).unwrap_or_revert());
+}
+
+/// Get the allowlisted presale of a launch, if it has one
+/// Returns nested tuple: (merkle_root, (public_start_time, presale_cap))
+#[no_mangle]
+pub extern "C" fn get_launch_presale() {
+    let launch_id: u64 = runtime::get_named_arg("launch_id");
+    let count: u64 = read_from_uref(LAUNCH_COUNT);
+
+    if launch_id >= count {
+        runtime::revert(casper_types::ApiError::User(ERROR_INDEX_OUT_OF_BOUNDS));
+    }
+
+    let presales_uref = get_dictionary_uref(LAUNCH_PRESALES);
+    let presale: Option<([u8; 32], (u64, U512))> =
+        storage::dictionary_get(presales_uref, &launch_id.to_string()).unwrap_or_default();
+
+    runtime::ret(CLValue::from_t(presale).unwrap_or_revert());
+}
+
+/// Get the control points of a Piecewise launch curve
+/// Returns list of (progress_bps, price) pairs
+#[no_mangle]
+pub extern "C" fn get_launch_curve_points() {
+    let launch_id: u64 = runtime::get_named_arg("launch_id");
+    let count: u64 = read_from_uref(LAUNCH_COUNT);
+
+    if launch_id >= count {
+        runtime::revert(casper_types::ApiError::User(ERROR_INDEX_OUT_OF_BOUNDS));
+    }
+
+    let points_uref = get_dictionary_uref(LAUNCH_CURVE_POINTS);
+    let points: Option<Vec<(u64, U512)>> =
+        storage::dictionary_get(points_uref, &launch_id.to_string()).unwrap_or_default();
+
+    runtime::ret(CLValue::from_t(points).unwrap_or_revert());
+}
+
+/// Get the creator allocation and vesting schedule of a launch, if any
+/// Returns nested tuple: (allocation_bps, (cliff_ms, duration_ms))
+#[no_mangle]
+pub extern "C" fn get_launch_vesting() {
+    let launch_id: u64 = runtime::get_named_arg("launch_id");
+    let count: u64 = read_from_uref(LAUNCH_COUNT);
+
+    if launch_id >= count {
+        runtime::revert(casper_types::ApiError::User(ERROR_INDEX_OUT_OF_BOUNDS));
+    }
+
+    let vesting_uref = get_dictionary_uref(LAUNCH_VESTING);
+    let vesting: Option<(u64, (u64, u64))> =
+        storage::dictionary_get(vesting_uref, &launch_id.to_string()).unwrap_or_default();
+
+    runtime::ret(CLValue::from_t(vesting).unwrap_or_revert());
+}
+
+/// Get the dev buy requested for a launch, if any. The factory only records
+/// it; the buy runs, and its tokens reach the creator, when the curve is
+/// installed with the same `initial_buy_cspr`.
+#[no_mangle]
+pub extern "C" fn get_launch_initial_buy() {
+    let launch_id: u64 = runtime::get_named_arg("launch_id");
+    let count: u64 = read_from_uref(LAUNCH_COUNT);
+
+    if launch_id >= count {
+        runtime::revert(casper_types::ApiError::User(ERROR_INDEX_OUT_OF_BOUNDS));
+    }
+
+    let initial_buys_uref = get_dictionary_uref(LAUNCH_INITIAL_BUYS);
+    let initial_buy: Option<U512> =
+        storage::dictionary_get(initial_buys_uref, &launch_id.to_string()).unwrap_or_default();
+
+    runtime::ret(CLValue::from_t(initial_buy).unwrap_or_revert());
+}
+
+/// Get when a launch opens and when it must graduate by
+/// Returns nested tuple: (start_time, deadline)
+#[no_mangle]
+pub extern "C" fn get_launch_schedule() {
+    let launch_id: u64 = runtime::get_named_arg("launch_id");
+    let count: u64 = read_from_uref(LAUNCH_COUNT);
+
+    if launch_id >= count {
+        runtime::revert(casper_types::ApiError::User(ERROR_INDEX_OUT_OF_BOUNDS));
+    }
+
+    runtime::ret(CLValue::from_t(read_schedule(launch_id)).unwrap_or_revert());
+}
+
+/// Get the CEP-18 quote token a launch is priced in; `None` for CSPR
+#[no_mangle]
+pub extern "C" fn get_launch_quote_token() {
+    let launch_id: u64 = runtime::get_named_arg("launch_id");
+    let count: u64 = read_from_uref(LAUNCH_COUNT);
+
+    if launch_id >= count {
+        runtime::revert(casper_types::ApiError::User(ERROR_INDEX_OUT_OF_BOUNDS));
+    }
+
+    let quote_tokens_uref = get_dictionary_uref(LAUNCH_QUOTE_TOKENS);
+    let quote_token: Option<Key> =
+        storage::dictionary_get(quote_tokens_uref, &launch_id.to_string()).unwrap_or_default();
+
+    runtime::ret(CLValue::from_t(quote_token).unwrap_or_revert());
+}
+
 /// Get launch ID by token hash
 #[no_mangle]
 pub extern "C" fn get_launch_by_token() {
@@ -193,7 +406,8 @@ pub extern "C" fn get_launch_by_token() {
 }
 
 /// Get multiple launches (paginated)
-/// Returns array of launch IDs in the specified range
+/// Returns array of launch IDs in the specified range, excluding launches
+/// that have not opened yet (see `get_upcoming_launches`)
 #[no_mangle]
 pub extern "C" fn get_launches() {
     let offset: u64 = runtime::get_named_arg("offset");
@@ -201,6 +415,7 @@ pub extern "C" fn get_launches() {
     let _status_filter: Option<u8> = runtime::get_named_arg("status_filter");
 
     let count: u64 = read_from_uref(LAUNCH_COUNT);
+    let now = get_current_time();
 
     let mut result: Vec<u64> = Vec::new();
     let mut checked = 0u64;
@@ -209,8 +424,37 @@ pub extern "C" fn get_launches() {
     // Simple pagination without status filter for now
     // Status filtering would require additional dictionary lookups
     while checked < limit && index < count {
-        result.push(index);
-        checked += 1;
+        if !is_upcoming(index, now) {
+            result.push(index);
+            checked += 1;
+        }
+        index += 1;
+    }
+
+    runtime::ret(CLValue::from_t(result).unwrap_or_revert());
+}
+
+/// Get launches scheduled to open in the future (paginated over launch IDs)
+/// Cancelled launches are not listed
+#[no_mangle]
+pub extern "C" fn get_upcoming_launches() {
+    let offset: u64 = runtime::get_named_arg("offset");
+    let limit: u64 = runtime::get_named_arg("limit");
+
+    let count: u64 = read_from_uref(LAUNCH_COUNT);
+    let now = get_current_time();
+    let meta_uref = get_dictionary_uref(LAUNCHES_META);
+
+    let mut result: Vec<u64> = Vec::new();
+    let mut index = offset;
+    while (result.len() as u64) < limit && index < count {
+        if is_upcoming(index, now) {
+            let meta: Option<(String, String, (u8, u8, u64))> =
+                storage::dictionary_get(meta_uref, &index.to_string()).unwrap_or_default();
+            if meta.map_or(false, |(_, _, (_, status, _))| status != STATUS_CANCELLED) {
+                result.push(index);
+            }
+        }
         index += 1;
     }
 
@@ -225,6 +469,8 @@ pub extern "C" fn create_launch() {
     let name: String = runtime::get_named_arg("name");
     let symbol: String = runtime::get_named_arg("symbol");
     let curve_type: u8 = runtime::get_named_arg("curve_type");
+    // Required for Piecewise curves: up to 16 (progress_bps, price) points
+    let curve_points: Option<Vec<(u64, U512)>> = runtime::get_named_arg("curve_points");
 
     // Optional overrides
     let graduation_threshold: Option<U512> = runtime::get_named_arg("graduation_threshold");
@@ -233,6 +479,39 @@ pub extern "C" fn create_launch() {
     let promo_budget: U512 = runtime::get_named_arg::<Option<U512>>("promo_budget")
         .unwrap_or(U512::zero());
 
+    // Optional anti-sniping protections (unset disables each one)
+    let snipe_window_minutes: Option<u64> = runtime::get_named_arg("snipe_window_minutes");
+    let max_wallet_cspr: Option<U512> = runtime::get_named_arg("max_wallet_cspr");
+    let max_holding_bps: Option<u64> = runtime::get_named_arg("max_holding_bps");
+    let buy_cooldown_seconds: Option<u64> = runtime::get_named_arg("buy_cooldown_seconds");
+
+    // Optional allowlisted presale (Merkle root of allowed accounts)
+    let presale_merkle_root: Option<[u8; 32]> = runtime::get_named_arg("presale_merkle_root");
+    let presale_minutes: Option<u64> = runtime::get_named_arg("presale_minutes");
+    let presale_cap: Option<U512> = runtime::get_named_arg("presale_cap");
+
+    // Optional creator allocation, vested after graduation
+    let creator_allocation_bps: Option<u64> = runtime::get_named_arg("creator_allocation_bps");
+    let vesting_cliff_days: Option<u64> = runtime::get_named_arg("vesting_cliff_days");
+    let vesting_duration_days: Option<u64> = runtime::get_named_arg("vesting_duration_days");
+
+    // Optional dev buy. Launches here are records with placeholder token and curve
+    // keys, so the factory validates and stores the amount but cannot execute it:
+    // the curve install performs the buy before anyone else can trade
+    let initial_buy_cspr: Option<U512> = runtime::get_named_arg("initial_buy_cspr");
+
+    // Optional scheduled opening; the deadline and presale are measured from it
+    let start_time: Option<u64> = runtime::get_named_arg("start_time");
+    let current_time = get_current_time();
+    if start_time.map_or(false, |start| start < current_time) {
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_START_TIME));
+    }
+    let start_time = start_time.unwrap_or(current_time);
+
+    // Optional 18-decimal CEP-18 quote token (e.g. ECTO or WETH); amounts such
+    // as the threshold and wallet cap are then in its base units
+    let quote_token: Option<Key> = runtime::get_named_arg("quote_token");
+
     // Optional metadata
     let _description: Option<String> = runtime::get_named_arg("description");
     let _website: Option<String> = runtime::get_named_arg("website");
@@ -245,9 +524,40 @@ pub extern "C" fn create_launch() {
     if symbol.is_empty() || symbol.len() > 6 {
         runtime::revert(casper_types::ApiError::User(ERROR_INVALID_SYMBOL));
     }
-    if curve_type > 2 {
+    if curve_type > CURVE_TYPE_MAX {
         runtime::revert(casper_types::ApiError::User(ERROR_INVALID_CURVE_TYPE));
     }
+    let points_valid = match (&curve_points, curve_type == CURVE_TYPE_PIECEWISE) {
+        (Some(points), true) => valid_control_points(points),
+        (None, false) => true,
+        _ => false,
+    };
+    if !points_valid {
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_CURVE_POINTS));
+    }
+    if max_holding_bps.unwrap_or(0) > 10000 {
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_PROTECTION));
+    }
+    if max_wallet_cspr.is_some() && snipe_window_minutes.unwrap_or(0) == 0 {
+        // A wallet cap only applies inside the opening window
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_PROTECTION));
+    }
+    if presale_merkle_root.is_some() != presale_minutes.map_or(false, |minutes| minutes > 0) {
+        // A presale needs both an allowlist and a duration
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_PRESALE));
+    }
+    if quote_token.is_some() && !promo_budget.is_zero() {
+        // The promo escrow is held in CSPR
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_QUOTE_TOKEN));
+    }
+    if let Some(initial_buy) = initial_buy_cspr {
+        // The dev buy lands inside the opening window, so the wallet cap applies to it
+        let over_wallet_cap = max_wallet_cspr.map_or(false, |cap| initial_buy > cap);
+        // and it cannot run before a scheduled launch opens
+        if initial_buy.is_zero() || over_wallet_cap || start_time > current_time {
+            runtime::revert(casper_types::ApiError::User(ERROR_INVALID_INITIAL_BUY));
+        }
+    }
 
     let creator = Key::Account(runtime::get_caller());
     let controller: Key = read_from_uref(CONTROLLER);
@@ -263,14 +573,31 @@ pub extern "C" fn create_launch() {
             (U512::from(50_000_000_000_000u64), 100u64, 30u64)
         };
 
+    // Creator allocation is capped by the controller
+    let allocation_bps = creator_allocation_bps.unwrap_or(0);
+    if allocation_bps > 0 {
+        let max_allocation_bps: u64 = if let Key::AddressableEntity(entity_addr) = controller {
+            let controller_contract = AddressableEntityHash::new(entity_addr.value());
+            runtime::call_contract(
+                controller_contract.into(),
+                "max_creator_allocation_bps",
+                runtime_args! {},
+            )
+        } else {
+            DEFAULT_MAX_CREATOR_ALLOCATION_BPS
+        };
+        if allocation_bps > max_allocation_bps {
+            runtime::revert(casper_types::ApiError::User(ERROR_ALLOCATION_TOO_HIGH));
+        }
+    }
+
     // Use overrides or defaults
     let final_threshold = graduation_threshold.unwrap_or(default_threshold);
     let final_creator_fee = creator_fee_bps.unwrap_or(0u64); // Creator fee defaults to 0
     let final_deadline_days = deadline_days.unwrap_or(default_deadline_days);
 
-    // Calculate deadline timestamp
-    let current_time = get_current_time();
-    let deadline = current_time + (final_deadline_days * 24 * 60 * 60 * 1000); // Convert days to milliseconds
+    // Calculate deadline timestamp from the start
+    let deadline = start_time + (final_deadline_days * 24 * 60 * 60 * 1000); // Convert days to milliseconds
 
     // Deploy token contract
     // Note: In practice, this would use runtime::put_key and stored contract WASM
@@ -298,6 +625,62 @@ pub extern "C" fn create_launch() {
     let meta_data = (name, symbol, (curve_type, STATUS_ACTIVE, current_time));
     storage::dictionary_put(meta_uref, &launch_id.to_string(), meta_data);
 
+    // Store creator allocation and vesting schedule (milliseconds from graduation)
+    if allocation_bps > 0 {
+        let vesting_uref = get_dictionary_uref(LAUNCH_VESTING);
+        let day_ms = 24 * 60 * 60 * 1000;
+        let vesting = (
+            allocation_bps,
+            (
+                vesting_cliff_days.unwrap_or(0) * day_ms,
+                vesting_duration_days.unwrap_or(0) * day_ms,
+            ),
+        );
+        storage::dictionary_put(vesting_uref, &launch_id.to_string(), vesting);
+    }
+
+    // Store when the launch opens and its deadline
+    let schedules_uref = get_dictionary_uref(LAUNCH_SCHEDULES);
+    storage::dictionary_put(schedules_uref, &launch_id.to_string(), (start_time, deadline));
+
+    // Store the quote token the curve is priced in
+    if let Some(token) = quote_token {
+        let quote_tokens_uref = get_dictionary_uref(LAUNCH_QUOTE_TOKENS);
+        storage::dictionary_put(quote_tokens_uref, &launch_id.to_string(), token);
+    }
+
+    // Store the dev buy for the curve install; no tokens are bought here
+    if let Some(initial_buy) = initial_buy_cspr {
+        let initial_buys_uref = get_dictionary_uref(LAUNCH_INITIAL_BUYS);
+        storage::dictionary_put(initial_buys_uref, &launch_id.to_string(), initial_buy);
+    }
+
+    // Store custom curve points
+    if let Some(points) = curve_points {
+        let points_uref = get_dictionary_uref(LAUNCH_CURVE_POINTS);
+        storage::dictionary_put(points_uref, &launch_id.to_string(), points);
+    }
+
+    // Store anti-sniping protections, converted to milliseconds for the curve
+    let protections_uref = get_dictionary_uref(LAUNCH_PROTECTIONS);
+    let protections = (
+        max_wallet_cspr.unwrap_or(U512::zero()),
+        (
+            snipe_window_minutes.unwrap_or(0) * 60 * 1000,
+            max_holding_bps.unwrap_or(0),
+            buy_cooldown_seconds.unwrap_or(0) * 1000,
+        ),
+    );
+    storage::dictionary_put(protections_uref, &launch_id.to_string(), protections);
+
+    // Store presale phase; public trading opens when it ends
+    if let (Some(root), Some(minutes)) = (presale_merkle_root, presale_minutes) {
+        let presales_uref = get_dictionary_uref(LAUNCH_PRESALES);
+        let public_start_time = start_time + minutes * 60 * 1000;
+        let presale = (root, (public_start_time, presale_cap.unwrap_or(U512::zero())));
+        storage::dictionary_put(presales_uref, &launch_id.to_string(), presale);
+    }
+
     // Map token to launch
     let token_to_launch_uref = get_dictionary_uref(TOKEN_TO_LAUNCH);
     let token_key = key_to_str(&token_placeholder);
@@ -312,6 +695,53 @@ pub extern "C" fn create_launch() {
     );
 }
 
+/// Move a scheduled launch to a new start time before it opens
+/// The deadline and any presale window move with it
+#[no_mangle]
+pub extern "C" fn reschedule_launch() {
+    let launch_id: u64 = runtime::get_named_arg("launch_id");
+    let new_start: u64 = runtime::get_named_arg("start_time");
+
+    let (start_time, deadline) = require_creator_before_start(launch_id);
+    if new_start < get_current_time() {
+        runtime::revert(casper_types::ApiError::User(ERROR_INVALID_START_TIME));
+    }
+    let shift = |time: u64| {
+        if new_start >= start_time {
+            time + (new_start - start_time)
+        } else {
+            time.saturating_sub(start_time - new_start)
+        }
+    };
+
+    let launch_key = launch_id.to_string();
+    let schedules_uref = get_dictionary_uref(LAUNCH_SCHEDULES);
+    storage::dictionary_put(schedules_uref, &launch_key, (new_start, shift(deadline)));
+
+    let presales_uref = get_dictionary_uref(LAUNCH_PRESALES);
+    let presale: Option<([u8; 32], (u64, U512))> =
+        storage::dictionary_get(presales_uref, &launch_key).unwrap_or_default();
+    if let Some((root, (public_start_time, cap))) = presale {
+        storage::dictionary_put(presales_uref, &launch_key, (root, (shift(public_start_time), cap)));
+    }
+}
+
+/// Cancel a scheduled launch before it opens
+#[no_mangle]
+pub extern "C" fn cancel_launch() {
+    let launch_id: u64 = runtime::get_named_arg("launch_id");
+    require_creator_before_start(launch_id);
+
+    let meta_uref = get_dictionary_uref(LAUNCHES_META);
+    let launch_key = launch_id.to_string();
+    let meta: Option<(String, String, (u8, u8, u64))> =
+        storage::dictionary_get(meta_uref, &launch_key).unwrap_or_default();
+    if let Some((name, symbol, (curve_type, _, created_at))) = meta {
+        let meta_data = (name, symbol, (curve_type, STATUS_CANCELLED, created_at));
+        storage::dictionary_put(meta_uref, &launch_key, meta_data);
+    }
+}
+
 /// Update launch status (placeholder - status is stored in bonding curve contract)
 /// This entry point exists for future extensibility
 #[no_mangle]
@@ -402,6 +832,100 @@ fn get_entry_points() -> EntryPoints {
         EntryPointPayment::Caller,
     ));
 
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_launch_protections",
+        vec![Parameter::new("launch_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Tuple2([
+            Box::new(CLType::U512),
+            Box::new(CLType::Tuple3([
+                Box::new(CLType::U64),
+                Box::new(CLType::U64),
+                Box::new(CLType::U64),
+            ])),
+        ]))),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_launch_presale",
+        vec![Parameter::new("launch_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Tuple2([
+            Box::new(CLType::ByteArray(32)),
+            Box::new(CLType::Tuple2([Box::new(CLType::U64), Box::new(CLType::U512)])),
+        ]))),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_launch_curve_points",
+        vec![Parameter::new("launch_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::List(Box::new(CLType::Tuple2([
+            Box::new(CLType::U64),
+            Box::new(CLType::U512),
+        ]))))),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_launch_vesting",
+        vec![Parameter::new("launch_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Tuple2([
+            Box::new(CLType::U64),
+            Box::new(CLType::Tuple2([Box::new(CLType::U64), Box::new(CLType::U64)])),
+        ]))),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_launch_initial_buy",
+        vec![Parameter::new("launch_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::U512)),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_launch_schedule",
+        vec![Parameter::new("launch_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Tuple2([
+            Box::new(CLType::U64),
+            Box::new(CLType::U64),
+        ]))),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_upcoming_launches",
+        vec![
+            Parameter::new("offset", CLType::U64),
+            Parameter::new("limit", CLType::U64),
+        ],
+        CLType::List(Box::new(CLType::U64)),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "get_launch_quote_token",
+        vec![Parameter::new("launch_id", CLType::U64)],
+        CLType::Option(Box::new(CLType::Key)),
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
     entry_points.add_entry_point(EntryPoint::new(
         "get_launches",
         vec![
@@ -422,6 +946,13 @@ fn get_entry_points() -> EntryPoints {
             Parameter::new("name", CLType::String),
             Parameter::new("symbol", CLType::String),
             Parameter::new("curve_type", CLType::U8),
+            Parameter::new(
+                "curve_points",
+                CLType::Option(Box::new(CLType::List(Box::new(CLType::Tuple2([
+                    Box::new(CLType::U64),
+                    Box::new(CLType::U512),
+                ]))))),
+            ),
             Parameter::new("graduation_threshold", CLType::Option(Box::new(CLType::U512))),
             Parameter::new("creator_fee_bps", CLType::Option(Box::new(CLType::U64))),
             Parameter::new("deadline_days", CLType::Option(Box::new(CLType::U64))),
@@ -429,6 +960,22 @@ fn get_entry_points() -> EntryPoints {
             Parameter::new("description", CLType::Option(Box::new(CLType::String))),
             Parameter::new("website", CLType::Option(Box::new(CLType::String))),
             Parameter::new("twitter", CLType::Option(Box::new(CLType::String))),
+            Parameter::new("snipe_window_minutes", CLType::Option(Box::new(CLType::U64))),
+            Parameter::new("max_wallet_cspr", CLType::Option(Box::new(CLType::U512))),
+            Parameter::new("max_holding_bps", CLType::Option(Box::new(CLType::U64))),
+            Parameter::new("buy_cooldown_seconds", CLType::Option(Box::new(CLType::U64))),
+            Parameter::new(
+                "presale_merkle_root",
+                CLType::Option(Box::new(CLType::ByteArray(32))),
+            ),
+            Parameter::new("presale_minutes", CLType::Option(Box::new(CLType::U64))),
+            Parameter::new("presale_cap", CLType::Option(Box::new(CLType::U512))),
+            Parameter::new("creator_allocation_bps", CLType::Option(Box::new(CLType::U64))),
+            Parameter::new("vesting_cliff_days", CLType::Option(Box::new(CLType::U64))),
+            Parameter::new("vesting_duration_days", CLType::Option(Box::new(CLType::U64))),
+            Parameter::new("initial_buy_cspr", CLType::Option(Box::new(CLType::U512))),
+            Parameter::new("start_time", CLType::Option(Box::new(CLType::U64))),
+            Parameter::new("quote_token", CLType::Option(Box::new(CLType::Key))),
         ],
         CLType::Tuple3([
             Box::new(CLType::U64),
@@ -440,6 +987,27 @@ fn get_entry_points() -> EntryPoints {
         EntryPointPayment::Caller,
     ));
 
+    entry_points.add_entry_point(EntryPoint::new(
+        "reschedule_launch",
+        vec![
+            Parameter::new("launch_id", CLType::U64),
+            Parameter::new("start_time", CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
+    entry_points.add_entry_point(EntryPoint::new(
+        "cancel_launch",
+        vec![Parameter::new("launch_id", CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Called,
+        EntryPointPayment::Caller,
+    ));
+
     entry_points.add_entry_point(EntryPoint::new(
         "update_launch_status",
         vec![
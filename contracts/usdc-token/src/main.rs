@@ -16,6 +16,7 @@ use casper_types::{
     runtime_args, CLType, CLTyped, CLValue, EntryPointAccess, EntryPointPayment,
     EntryPointType, Key, Parameter, RuntimeArgs, URef, U256,
 };
+use ectoplasm_common::caller_key;
 
 // Storage keys
 const NAME: &str = "name";
@@ -200,8 +201,7 @@ pub extern "C" fn allowance() {
 pub extern "C" fn transfer() {
     let recipient: Key = runtime::get_named_arg("recipient");
     let amount: U256 = runtime::get_named_arg("amount");
-    let caller = runtime::get_caller();
-    let sender = Key::Account(caller);
+    let sender = caller_key();
     transfer_internal(&sender, &recipient, amount);
 }
 
@@ -211,8 +211,7 @@ pub extern "C" fn transfer_from() {
     let recipient: Key = runtime::get_named_arg("recipient");
     let amount: U256 = runtime::get_named_arg("amount");
 
-    let caller = runtime::get_caller();
-    let spender = Key::Account(caller);
+    let spender = caller_key();
 
     let current_allowance = read_allowance(&owner, &spender);
     if current_allowance < amount {
@@ -227,8 +226,7 @@ pub extern "C" fn transfer_from() {
 pub extern "C" fn approve() {
     let spender: Key = runtime::get_named_arg("spender");
     let amount: U256 = runtime::get_named_arg("amount");
-    let caller = runtime::get_caller();
-    let owner = Key::Account(caller);
+    let owner = caller_key();
     write_allowance(&owner, &spender, amount);
 }
 